@@ -1,5 +1,5 @@
 import * as anchor from "@anchor-lang/anchor";
-import { Program, AnchorProvider, BN } from "@anchor-lang/anchor";
+import { Program, AnchorProvider, BN, IdlTypes } from "@anchor-lang/anchor";
 import {
   PublicKey,
   Keypair,
@@ -28,7 +28,13 @@ export const USDC_MINT = new PublicKey(
 // Constants
 export const GLOBAL_STATE_SEED = "global_state";
 export const USER_PROFILE_SEED = "user_profile";
+export const REFERRER_CODE_SEED = "referrer_code";
 export const MARKET_SEED = "market";
+export const VAULT_SEED = "vault";
+export const OUTCOME_MINT_SEED = "outcome_mint";
+export const COST_BASIS_SEED = "cost_basis";
+
+export type CreateMarketParams = IdlTypes<BelievMarket>["createMarketParams"];
 
 export class BelievMarketSDK {
   private program: Program<BelievMarket>;
@@ -41,7 +47,7 @@ export class BelievMarketSDK {
     this.provider = provider;
     // In a real implementation, we would load the IDL from a file
     const idl = null as any; // TODO: Load actual IDL
-    this.program = new Program(idl, programId, provider);
+    this.program = new Program({ ...idl, address: programId.toBase58() }, provider);
   }
 
   /**
@@ -65,20 +71,69 @@ export class BelievMarketSDK {
   }
 
   /**
-   * Get market PDA
+   * Get referrer code registry PDA
+   */
+  static getReferrerCodePda(referrerCode: string): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(REFERRER_CODE_SEED), Buffer.from(referrerCode.toLowerCase())],
+      PROGRAM_ID
+    );
+  }
+
+  /**
+   * Get market PDA from its sequential market ID
+   */
+  static getMarketPda(marketId: BN | number): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(MARKET_SEED), new BN(marketId).toArrayLike(Buffer, "le", 8)],
+      PROGRAM_ID
+    );
+  }
+
+  /**
+   * Get market vault PDA
    */
-  static getMarketPda(
-    creator: PublicKey,
-    resolveAt: number
+  static getVaultPda(market: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(VAULT_SEED), market.toBuffer()],
+      PROGRAM_ID
+    );
+  }
+
+  /**
+   * Get the share mint PDA of an outcome
+   */
+  static getOutcomeMintPda(
+    market: PublicKey,
+    outcomeIndex: number
   ): [PublicKey, number] {
-    const resolveAtBuffer = Buffer.alloc(8);
-    resolveAtBuffer.writeBigInt64LE(BigInt(resolveAt), 0);
     return PublicKey.findProgramAddressSync(
-      [Buffer.from(MARKET_SEED), creator.toBuffer(), resolveAtBuffer],
+      [Buffer.from(OUTCOME_MINT_SEED), market.toBuffer(), Buffer.from([outcomeIndex])],
       PROGRAM_ID
     );
   }
 
+  /**
+   * Get a holder's cost basis PDA in a market
+   */
+  static getCostBasisPda(
+    market: PublicKey,
+    owner: PublicKey
+  ): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from(COST_BASIS_SEED), market.toBuffer(), owner.toBuffer()],
+      PROGRAM_ID
+    );
+  }
+
+  /**
+   * Get the outcome book of a market
+   */
+  private async getOutcomeBook(market: PublicKey): Promise<PublicKey> {
+    const marketAccount = await this.program.account.market.fetch(market);
+    return marketAccount.outcomeBook;
+  }
+
   /**
    * Initialize global program state
    */
@@ -109,12 +164,14 @@ export class BelievMarketSDK {
     referrerCode: string
   ): Promise<string> {
     const [userProfile] = BelievMarketSDK.getUserProfilePda(user.publicKey);
+    const [referrerCodeAccount] = BelievMarketSDK.getReferrerCodePda(referrerCode);
 
     const tx = await this.program.methods
       .initializeUser(referrerCode)
       .accounts({
         user: user.publicKey,
         userProfile,
+        referrerCodeAccount,
         systemProgram: SystemProgram.programId,
       })
       .signers([user])
@@ -124,41 +181,101 @@ export class BelievMarketSDK {
   }
 
   /**
-   * Create a new market
+   * Register the referrer code of a profile created before the code registry
+   * Only the global authority may call this
+   */
+  async backfillReferrerCode(
+    authority: Signer,
+    user: PublicKey
+  ): Promise<string> {
+    const [globalState] = BelievMarketSDK.getGlobalStatePda();
+    const [userProfile] = BelievMarketSDK.getUserProfilePda(user);
+    const { referrerCode } = await this.program.account.userProfile.fetch(
+      userProfile
+    );
+    const [referrerCodeAccount] = BelievMarketSDK.getReferrerCodePda(referrerCode);
+
+    const tx = await this.program.methods
+      .backfillReferrerCode()
+      .accounts({
+        authority: authority.publicKey,
+        userProfile,
+        referrerCodeAccount,
+        globalState,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([authority])
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Create a new market under the next market ID
+   * The outcome book is allocated in the same transaction. Each outcome's
+   * share mint must then be created with `initializeOutcomeMint` before the
+   * market opens for trading.
    */
   async createMarket(
     creator: Signer,
-    numOutcomes: number,
-    outcomeLabels: string[],
-    tags: string[],
-    tradingFeeBps: number,
-    resolveAt: number,
+    params: CreateMarketParams,
+    collateralMint: PublicKey,
     creatorTokenAccount: PublicKey,
     feeDestination: PublicKey
   ): Promise<string> {
     const [globalState] = BelievMarketSDK.getGlobalStatePda();
     const [userProfile] = BelievMarketSDK.getUserProfilePda(creator.publicKey);
-    const [market] = BelievMarketSDK.getMarketPda(creator.publicKey, resolveAt);
+    const { marketCount } = await this.program.account.globalState.fetch(globalState);
+    const [market] = BelievMarketSDK.getMarketPda(marketCount.addn(1));
+    const [marketTokenAccount] = BelievMarketSDK.getVaultPda(market);
+    const outcomeBook = Keypair.generate();
 
     const tx = await this.program.methods
-      .createMarket(
-        numOutcomes,
-        outcomeLabels,
-        tags,
-        tradingFeeBps,
-        new BN(resolveAt)
-      )
+      .createMarket(params)
       .accounts({
         creator: creator.publicKey,
         creatorTokenAccount,
+        collateralMint,
         feeDestination,
         globalState,
         creatorProfile: userProfile,
         market,
+        outcomeBook: outcomeBook.publicKey,
+        marketTokenAccount,
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
-      .signers([creator])
+      .preInstructions([
+        await this.program.account.outcomeBook.createInstruction(outcomeBook),
+      ])
+      .signers([creator, outcomeBook])
+      .rpc();
+
+    return tx;
+  }
+
+  /**
+   * Create the share mint of one outcome
+   */
+  async initializeOutcomeMint(
+    payer: Signer,
+    market: PublicKey,
+    outcomeIndex: number
+  ): Promise<string> {
+    const outcomeBook = await this.getOutcomeBook(market);
+    const [outcomeMint] = BelievMarketSDK.getOutcomeMintPda(market, outcomeIndex);
+
+    const tx = await this.program.methods
+      .initializeOutcomeMint(outcomeIndex)
+      .accounts({
+        payer: payer.publicKey,
+        market,
+        outcomeBook,
+        outcomeMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([payer])
       .rpc();
 
     return tx;
@@ -166,26 +283,51 @@ export class BelievMarketSDK {
 
   /**
    * Buy outcome shares
+   * Fails if fewer than `minSharesOut` shares would be received or, when set,
+   * once `deadline` (unix seconds) has passed
    */
   async buyOutcome(
     buyer: Signer,
     market: PublicKey,
     outcomeIndex: number,
     amountUsdc: number,
+    minSharesOut: number,
+    deadline: number | null,
     buyerTokenAccount: PublicKey,
     marketTokenAccount: PublicKey
   ): Promise<string> {
     const [globalState] = BelievMarketSDK.getGlobalStatePda();
+    const outcomeBook = await this.getOutcomeBook(market);
+    const [outcomeMint] = BelievMarketSDK.getOutcomeMintPda(market, outcomeIndex);
+    const buyerOutcomeAccount = getAssociatedTokenAddressSync(
+      outcomeMint,
+      buyer.publicKey
+    );
+    const [buyerCostBasis] = BelievMarketSDK.getCostBasisPda(
+      market,
+      buyer.publicKey
+    );
 
     const tx = await this.program.methods
-      .buyOutcome(outcomeIndex, new BN(amountUsdc))
+      .buyOutcome(
+        outcomeIndex,
+        new BN(amountUsdc),
+        new BN(minSharesOut),
+        deadline === null ? null : new BN(deadline)
+      )
       .accounts({
         buyer: buyer.publicKey,
         buyerTokenAccount,
         marketTokenAccount,
         market,
+        outcomeBook,
         globalState,
+        outcomeMint,
+        buyerOutcomeAccount,
+        buyerCostBasis,
         tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
       })
       .signers([buyer])
       .rpc();
@@ -195,28 +337,52 @@ export class BelievMarketSDK {
 
   /**
    * Sell outcome shares
+   * Fails if less than `minAmountOut` would be received or, when set, once
+   * `deadline` (unix seconds) has passed
    */
   async sellOutcome(
     seller: Signer,
     market: PublicKey,
     outcomeIndex: number,
     sharesToSell: number,
+    minAmountOut: number,
+    deadline: number | null,
     sellerTokenAccount: PublicKey,
     marketTokenAccount: PublicKey
   ): Promise<string> {
     const [globalState] = BelievMarketSDK.getGlobalStatePda();
     const marketVaultAuthority = market; // Same PDA acts as vault authority
+    const outcomeBook = await this.getOutcomeBook(market);
+    const [outcomeMint] = BelievMarketSDK.getOutcomeMintPda(market, outcomeIndex);
+    const sellerOutcomeAccount = getAssociatedTokenAddressSync(
+      outcomeMint,
+      seller.publicKey
+    );
+    const [sellerCostBasis] = BelievMarketSDK.getCostBasisPda(
+      market,
+      seller.publicKey
+    );
 
     const tx = await this.program.methods
-      .sellOutcome(outcomeIndex, new BN(sharesToSell))
+      .sellOutcome(
+        outcomeIndex,
+        new BN(sharesToSell),
+        new BN(minAmountOut),
+        deadline === null ? null : new BN(deadline)
+      )
       .accounts({
         seller: seller.publicKey,
         sellerTokenAccount,
         marketTokenAccount,
         market,
+        outcomeBook,
         marketVaultAuthority,
         globalState,
+        outcomeMint,
+        sellerOutcomeAccount,
+        sellerCostBasis,
         tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
       })
       .signers([seller])
       .rpc();
@@ -225,23 +391,28 @@ export class BelievMarketSDK {
   }
 
   /**
-   * Resolve a market
+   * Resolve a market with a payout vector
+   * Outcome `i` pays `payoutNumerators[i] / payoutDenominator`; the numerators
+   * must sum to the denominator. Only the market's designated resolver may call.
    */
   async resolveMarket(
-    authority: Signer,
+    resolver: Signer,
     market: PublicKey,
-    winningOutcome: number
+    payoutNumerators: number[],
+    payoutDenominator: number
   ): Promise<string> {
     const [globalState] = BelievMarketSDK.getGlobalStatePda();
+    const outcomeBook = await this.getOutcomeBook(market);
 
     const tx = await this.program.methods
-      .resolveMarket(winningOutcome)
+      .resolveMarket(payoutNumerators, payoutDenominator)
       .accounts({
-        authority: authority.publicKey,
+        resolver: resolver.publicKey,
         market,
+        outcomeBook,
         globalState,
       })
-      .signers([authority])
+      .signers([resolver])
       .rpc();
 
     return tx;
@@ -249,26 +420,37 @@ export class BelievMarketSDK {
 
   /**
    * Redeem winnings
+   * Burns every share of `outcomeIndex` held in the winner's associated token
+   * account and pays the outcome's share of the payout vector
    */
   async redeemWinnings(
     winner: Signer,
     market: PublicKey,
-    winningShares: number,
+    outcomeIndex: number,
     winnerTokenAccount: PublicKey,
     marketTokenAccount: PublicKey
   ): Promise<string> {
     const [globalState] = BelievMarketSDK.getGlobalStatePda();
     const marketVaultAuthority = market;
+    const outcomeBook = await this.getOutcomeBook(market);
+    const [outcomeMint] = BelievMarketSDK.getOutcomeMintPda(market, outcomeIndex);
+    const winnerOutcomeAccount = getAssociatedTokenAddressSync(
+      outcomeMint,
+      winner.publicKey
+    );
 
     const tx = await this.program.methods
-      .redeemWinnings(new BN(winningShares))
+      .redeemWinnings(outcomeIndex)
       .accounts({
         winner: winner.publicKey,
         winnerTokenAccount,
         marketTokenAccount,
         market,
+        outcomeBook,
         marketVaultAuthority,
         globalState,
+        outcomeMint,
+        winnerOutcomeAccount,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .signers([winner])
@@ -277,6 +459,44 @@ export class BelievMarketSDK {
     return tx;
   }
 
+  /**
+   * Claim a refund from a voided market
+   * Refunds the holder's net cost basis in the market and closes their cost
+   * basis account; outcome tokens are left in place
+   */
+  async claimRefund(
+    holder: Signer,
+    market: PublicKey,
+    holderTokenAccount: PublicKey,
+    marketTokenAccount: PublicKey
+  ): Promise<string> {
+    const [globalState] = BelievMarketSDK.getGlobalStatePda();
+    const marketVaultAuthority = market;
+    const outcomeBook = await this.getOutcomeBook(market);
+    const [costBasis] = BelievMarketSDK.getCostBasisPda(
+      market,
+      holder.publicKey
+    );
+
+    const tx = await this.program.methods
+      .claimRefund()
+      .accounts({
+        holder: holder.publicKey,
+        holderTokenAccount,
+        costBasis,
+        marketTokenAccount,
+        market,
+        outcomeBook,
+        marketVaultAuthority,
+        globalState,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([holder])
+      .rpc();
+
+    return tx;
+  }
+
   /**
    * Claim creator peg
    */
@@ -289,6 +509,8 @@ export class BelievMarketSDK {
     const [globalState] = BelievMarketSDK.getGlobalStatePda();
     const marketVaultAuthority = market;
 
+    const outcomeBook = await this.getOutcomeBook(market);
+
     const tx = await this.program.methods
       .claimPeg()
       .accounts({
@@ -296,6 +518,7 @@ export class BelievMarketSDK {
         creatorTokenAccount,
         marketTokenAccount,
         market,
+        outcomeBook,
         marketVaultAuthority,
         globalState,
         tokenProgram: TOKEN_PROGRAM_ID,
@@ -308,6 +531,7 @@ export class BelievMarketSDK {
 
   /**
    * Withdraw fees
+   * `invitorTokenAccount` is only paid when the market has an invitor
    */
   async withdrawFees(
     caller: Signer,
@@ -320,6 +544,8 @@ export class BelievMarketSDK {
     const [globalState] = BelievMarketSDK.getGlobalStatePda();
     const marketVaultAuthority = market;
 
+    const outcomeBook = await this.getOutcomeBook(market);
+
     const tx = await this.program.methods
       .withdrawFees()
       .accounts({
@@ -329,6 +555,7 @@ export class BelievMarketSDK {
         platformTokenAccount,
         marketTokenAccount,
         market,
+        outcomeBook,
         marketVaultAuthority,
         globalState,
         tokenProgram: TOKEN_PROGRAM_ID,
@@ -339,6 +566,50 @@ export class BelievMarketSDK {
     return tx;
   }
 
+  /**
+   * Close a settled market once nothing is owed, returning rent to its creator
+   * Passes every initialized outcome mint so their supply can be checked and
+   * their mint authority revoked
+   */
+  async closeMarket(
+    caller: Signer,
+    market: PublicKey,
+    platformTokenAccount: PublicKey
+  ): Promise<string> {
+    const [globalState] = BelievMarketSDK.getGlobalStatePda();
+    const marketAccount = await this.program.account.market.fetch(market);
+    const book = await this.program.account.outcomeBook.fetch(
+      marketAccount.outcomeBook
+    );
+    const outcomeMints = book.outcomeMints
+      .slice(0, book.numOutcomes)
+      .filter((mint) => !mint.equals(PublicKey.default));
+
+    const tx = await this.program.methods
+      .closeMarket()
+      .accounts({
+        caller: caller.publicKey,
+        creator: marketAccount.creator,
+        market,
+        outcomeBook: marketAccount.outcomeBook,
+        marketTokenAccount: marketAccount.vault,
+        platformTokenAccount,
+        globalState,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .remainingAccounts(
+        outcomeMints.map((pubkey) => ({
+          pubkey,
+          isWritable: true,
+          isSigner: false,
+        }))
+      )
+      .signers([caller])
+      .rpc();
+
+    return tx;
+  }
+
   /**
    * Emergency pause
    */
@@ -379,10 +650,13 @@ export class BelievMarketSDK {
    * Get market odds
    */
   async getMarketOdds(market: PublicKey): Promise<any> {
+    const outcomeBook = await this.getOutcomeBook(market);
+
     const tx = await this.program.methods
       .getMarketOdds()
       .accounts({
         market,
+        outcomeBook,
       })
       .view();
 
@@ -391,13 +665,33 @@ export class BelievMarketSDK {
 
   /**
    * Get user position
+   * Reads the user's associated token account for each outcome's share mint
    */
-  async getUserPosition(market: PublicKey): Promise<any> {
+  async getUserPosition(market: PublicKey, user: PublicKey): Promise<any> {
+    const marketAccount = await this.program.account.market.fetch(market);
+    const outcomeAccounts = Array.from(
+      { length: marketAccount.numOutcomes },
+      (_, outcomeIndex) =>
+        getAssociatedTokenAddressSync(
+          BelievMarketSDK.getOutcomeMintPda(market, outcomeIndex)[0],
+          user
+        )
+    );
+
     const tx = await this.program.methods
       .getUserPosition()
       .accounts({
         market,
+        outcomeBook: marketAccount.outcomeBook,
+        user,
       })
+      .remainingAccounts(
+        outcomeAccounts.map((pubkey) => ({
+          pubkey,
+          isWritable: false,
+          isSigner: false,
+        }))
+      )
       .view();
 
     return tx;
@@ -1,162 +1,189 @@
+/**
+ * Program IDL in camelCase format in order to be used in JS/TS.
+ *
+ * Note that this is only a type helper and is not the actual IDL. The original
+ * IDL can be found at `target/idl/belief_market.json`.
+ */
 export type BelievMarket = {
-  version: "0.1.0";
-  name: "belief_market";
+  address: "11111111111111111111111111111111";
+  metadata: {
+    name: "beliefMarket";
+    version: "0.1.0";
+    spec: "0.1.0";
+    description: "Multi-outcome prediction market platform for Eclipse SVM";
+  };
   instructions: [
     {
-      name: "initializeGlobal";
+      name: "addCollateralMint";
+      docs: [
+        "Allow a collateral mint for new markets - only authority"
+      ];
+      discriminator: [65, 51, 37, 114, 219, 109, 46, 244];
       accounts: [
         {
           name: "authority";
-          isMut: true;
-          isSigner: true;
+          signer: true;
         },
         {
-          name: "globalState";
-          isMut: true;
-          isSigner: false;
+          name: "collateralMint";
         },
         {
-          name: "systemProgram";
-          isMut: false;
-          isSigner: false;
-        }
-      ];
-      args: [
-        {
-          name: "platformWallet";
-          type: "publicKey";
+          name: "globalState";
+          writable: true;
         }
       ];
+      args: [];
     },
     {
-      name: "initializeUser";
+      name: "backfillReferrerCode";
+      docs: [
+        "Register the referrer code of a profile created before the code registry - only authority"
+      ];
+      discriminator: [99, 143, 19, 198, 177, 60, 48, 10];
       accounts: [
         {
-          name: "user";
-          isMut: true;
-          isSigner: true;
+          name: "authority";
+          writable: true;
+          signer: true;
         },
         {
           name: "userProfile";
-          isMut: true;
-          isSigner: false;
+          writable: true;
         },
         {
-          name: "systemProgram";
-          isMut: false;
-          isSigner: false;
-        }
-      ];
-      args: [
+          name: "referrerCodeAccount";
+          docs: [
+            "Registry entry for the profile's normalized referrer code"
+          ];
+          writable: true;
+        },
         {
-          name: "referrerCode";
-          type: "string";
+          name: "globalState";
+        },
+        {
+          name: "systemProgram";
         }
       ];
+      args: [];
     },
     {
-      name: "createMarket";
+      name: "burnCompleteSet";
+      docs: [
+        "Burn complete sets of outcome shares back into USDC"
+      ];
+      discriminator: [183, 36, 119, 130, 123, 198, 110, 211];
       accounts: [
         {
-          name: "creator";
-          isMut: true;
-          isSigner: true;
+          name: "user";
+          writable: true;
+          signer: true;
         },
         {
-          name: "creatorTokenAccount";
-          isMut: true;
-          isSigner: false;
+          name: "userTokenAccount";
+          writable: true;
         },
         {
-          name: "feeDestination";
-          isMut: true;
-          isSigner: false;
+          name: "marketTokenAccount";
+          docs: [
+            "Market vault holding the collateral"
+          ];
+          writable: true;
         },
         {
-          name: "globalState";
-          isMut: false;
-          isSigner: false;
+          name: "market";
+          writable: true;
         },
         {
-          name: "creatorProfile";
-          isMut: false;
-          isSigner: false;
+          name: "outcomeBook";
+          writable: true;
         },
         {
-          name: "market";
-          isMut: true;
-          isSigner: false;
+          name: "marketVaultAuthority";
+          docs: [
+            "Market PDA used as authority for CPI"
+          ];
+        },
+        {
+          name: "globalState";
+        },
+        {
+          name: "userCostBasis";
+          docs: [
+            "The user's net cost in this market, refunded if it is voided"
+          ];
+          writable: true;
         },
         {
           name: "tokenProgram";
-          isMut: false;
-          isSigner: false;
         },
         {
           name: "systemProgram";
-          isMut: false;
-          isSigner: false;
         }
       ];
       args: [
         {
-          name: "numOutcomes";
-          type: "u8";
-        },
-        {
-          name: "outcomeLabels";
-          type: {
-            vec: "string";
-          };
-        },
-        {
-          name: "tags";
-          type: {
-            vec: "string";
-          };
-        },
-        {
-          name: "tradingFeeBps";
-          type: "u16";
-        },
-        {
-          name: "resolveAt";
-          type: "i64";
+          name: "amount";
+          type: "u64";
         }
       ];
     },
     {
       name: "buyOutcome";
+      docs: [
+        "Buy shares of an outcome"
+      ];
+      discriminator: [23, 167, 228, 249, 105, 241, 139, 113];
       accounts: [
         {
           name: "buyer";
-          isMut: true;
-          isSigner: true;
+          writable: true;
+          signer: true;
         },
         {
           name: "buyerTokenAccount";
-          isMut: true;
-          isSigner: false;
+          writable: true;
         },
         {
           name: "marketTokenAccount";
-          isMut: true;
-          isSigner: false;
+          docs: [
+            "Market vault holding the collateral"
+          ];
+          writable: true;
         },
         {
           name: "market";
-          isMut: true;
-          isSigner: false;
+          writable: true;
+        },
+        {
+          name: "outcomeBook";
+          writable: true;
         },
         {
           name: "globalState";
-          isMut: false;
-          isSigner: false;
+        },
+        {
+          name: "outcomeMint";
+          writable: true;
+        },
+        {
+          name: "buyerOutcomeAccount";
+          writable: true;
+        },
+        {
+          name: "buyerCostBasis";
+          docs: [
+            "The buyer's net cost in this market, refunded if it is voided"
+          ];
+          writable: true;
         },
         {
           name: "tokenProgram";
-          isMut: false;
-          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+        },
+        {
+          name: "systemProgram";
         }
       ];
       args: [
@@ -167,804 +194,4441 @@ export type BelievMarket = {
         {
           name: "amountUsdc";
           type: "u64";
+        },
+        {
+          name: "minSharesOut";
+          type: "u64";
+        },
+        {
+          name: "deadline";
+          type: {
+            option: "i64";
+          };
         }
       ];
     },
     {
-      name: "sellOutcome";
+      name: "cancelOrder";
+      docs: [
+        "Cancel a limit order and return its escrow"
+      ];
+      discriminator: [95, 129, 237, 240, 8, 49, 223, 132];
       accounts: [
         {
-          name: "seller";
-          isMut: true;
-          isSigner: true;
+          name: "owner";
+          writable: true;
+          signer: true;
         },
         {
-          name: "sellerTokenAccount";
-          isMut: true;
-          isSigner: false;
+          name: "ownerTokenAccount";
+          writable: true;
         },
         {
           name: "marketTokenAccount";
-          isMut: true;
-          isSigner: false;
+          docs: [
+            "Market vault holding the collateral"
+          ];
+          writable: true;
         },
         {
           name: "market";
-          isMut: true;
-          isSigner: false;
+          writable: true;
+        },
+        {
+          name: "outcomeBook";
         },
         {
           name: "marketVaultAuthority";
-          isMut: false;
-          isSigner: false;
+          docs: [
+            "Market PDA used as authority for CPI"
+          ];
+        },
+        {
+          name: "outcomeMint";
+          writable: true;
+        },
+        {
+          name: "ownerOutcomeAccount";
+          writable: true;
+        },
+        {
+          name: "order";
+          writable: true;
         },
         {
           name: "globalState";
-          isMut: false;
-          isSigner: false;
         },
         {
           name: "tokenProgram";
-          isMut: false;
-          isSigner: false;
         }
       ];
-      args: [
+      args: [];
+    },
+    {
+      name: "challengeResolution";
+      docs: [
+        "Challenge a resolution during its dispute window by posting a bond"
+      ];
+      discriminator: [5, 230, 48, 100, 46, 252, 35, 119];
+      accounts: [
         {
-          name: "outcomeIndex";
-          type: "u8";
+          name: "challenger";
+          signer: true;
         },
         {
-          name: "sharesToSell";
-          type: "u64";
+          name: "challengerTokenAccount";
+          writable: true;
+        },
+        {
+          name: "marketTokenAccount";
+          docs: [
+            "Market vault holding the collateral"
+          ];
+          writable: true;
+        },
+        {
+          name: "market";
+          writable: true;
+        },
+        {
+          name: "globalState";
+        },
+        {
+          name: "tokenProgram";
         }
       ];
+      args: [];
     },
     {
-      name: "resolveMarket";
+      name: "claimPeg";
+      docs: [
+        "Claim creator peg ($1 returned after resolution)"
+      ];
+      discriminator: [102, 59, 139, 95, 127, 206, 57, 7];
       accounts: [
         {
-          name: "authority";
-          isMut: false;
-          isSigner: true;
+          name: "creator";
+          signer: true;
+        },
+        {
+          name: "creatorTokenAccount";
+          writable: true;
+        },
+        {
+          name: "marketTokenAccount";
+          docs: [
+            "Market vault holding the collateral"
+          ];
+          writable: true;
         },
         {
           name: "market";
-          isMut: true;
-          isSigner: false;
+          writable: true;
+        },
+        {
+          name: "outcomeBook";
+          writable: true;
+        },
+        {
+          name: "marketVaultAuthority";
+          docs: [
+            "Market PDA used as authority for CPI"
+          ];
         },
         {
           name: "globalState";
-          isMut: false;
-          isSigner: false;
-        }
-      ];
-      args: [
+        },
         {
-          name: "winningOutcome";
-          type: "u8";
+          name: "tokenProgram";
         }
       ];
+      args: [];
     },
     {
-      name: "redeemWinnings";
+      name: "claimRefund";
+      docs: [
+        "Claim a refund of the caller's net cost in a voided market - permissionless"
+      ];
+      discriminator: [15, 16, 30, 161, 255, 228, 97, 60];
       accounts: [
         {
-          name: "winner";
-          isMut: true;
-          isSigner: true;
+          name: "holder";
+          writable: true;
+          signer: true;
         },
         {
-          name: "winnerTokenAccount";
-          isMut: true;
-          isSigner: false;
+          name: "holderTokenAccount";
+          writable: true;
+        },
+        {
+          name: "costBasis";
+          docs: [
+            "Holder's cost basis in the market, closed to the holder"
+          ];
+          writable: true;
         },
         {
           name: "marketTokenAccount";
-          isMut: true;
-          isSigner: false;
+          docs: [
+            "Market vault holding the collateral"
+          ];
+          writable: true;
         },
         {
           name: "market";
-          isMut: true;
-          isSigner: false;
+          writable: true;
+        },
+        {
+          name: "outcomeBook";
+          writable: true;
         },
         {
           name: "marketVaultAuthority";
-          isMut: false;
-          isSigner: false;
+          docs: [
+            "Market PDA used as authority for CPI"
+          ];
         },
         {
           name: "globalState";
-          isMut: false;
-          isSigner: false;
         },
         {
           name: "tokenProgram";
-          isMut: false;
-          isSigner: false;
-        }
-      ];
-      args: [
-        {
-          name: "winningShares";
-          type: "u64";
         }
       ];
+      args: [];
     },
     {
-      name: "claimPeg";
+      name: "closeMarket";
+      docs: [
+        "Close a fully settled market and reclaim its rent - permissionless"
+      ];
+      discriminator: [88, 154, 248, 186, 48, 14, 123, 244];
       accounts: [
+        {
+          name: "caller";
+          signer: true;
+        },
         {
           name: "creator";
-          isMut: true;
-          isSigner: true;
+          writable: true;
         },
         {
-          name: "creatorTokenAccount";
-          isMut: true;
-          isSigner: false;
+          name: "market";
+          writable: true;
         },
         {
-          name: "marketTokenAccount";
-          isMut: true;
-          isSigner: false;
+          name: "outcomeBook";
+          writable: true;
         },
         {
-          name: "market";
-          isMut: true;
-          isSigner: false;
+          name: "marketTokenAccount";
+          docs: [
+            "Market vault holding the collateral"
+          ];
+          writable: true;
         },
         {
-          name: "marketVaultAuthority";
-          isMut: false;
-          isSigner: false;
+          name: "platformTokenAccount";
+          writable: true;
         },
         {
           name: "globalState";
-          isMut: false;
-          isSigner: false;
         },
         {
           name: "tokenProgram";
-          isMut: false;
-          isSigner: false;
         }
       ];
       args: [];
     },
     {
-      name: "withdrawFees";
+      name: "closeTrading";
+      docs: [
+        "Halt trading on a market past its resolution time - permissionless"
+      ];
+      discriminator: [31, 69, 161, 86, 90, 124, 230, 175];
       accounts: [
         {
           name: "caller";
-          isMut: true;
-          isSigner: true;
+          signer: true;
+        },
+        {
+          name: "market";
+          writable: true;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "createMarket";
+      docs: [
+        "Create a new prediction market"
+      ];
+      discriminator: [103, 226, 97, 235, 200, 188, 251, 254];
+      accounts: [
+        {
+          name: "creator";
+          writable: true;
+          signer: true;
         },
         {
           name: "creatorTokenAccount";
-          isMut: true;
-          isSigner: false;
+          writable: true;
         },
         {
-          name: "invitorTokenAccount";
-          isMut: true;
-          isSigner: false;
+          name: "collateralMint";
         },
         {
-          name: "platformTokenAccount";
-          isMut: true;
-          isSigner: false;
+          name: "feeDestination";
+          writable: true;
         },
         {
-          name: "marketTokenAccount";
-          isMut: true;
-          isSigner: false;
+          name: "globalState";
+          writable: true;
+        },
+        {
+          name: "creatorProfile";
         },
         {
           name: "market";
-          isMut: true;
-          isSigner: false;
+          writable: true;
         },
         {
-          name: "marketVaultAuthority";
-          isMut: false;
-          isSigner: false;
+          name: "outcomeBook";
+          docs: [
+            "Per-outcome state of the market, allocated by the client (`OutcomeBook::LEN` bytes)"
+          ];
+          writable: true;
         },
         {
-          name: "globalState";
-          isMut: false;
-          isSigner: false;
+          name: "marketTokenAccount";
+          docs: [
+            "Market vault holding the collateral, owned by the market PDA"
+          ];
+          writable: true;
         },
         {
           name: "tokenProgram";
-          isMut: false;
-          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+        }
+      ];
+      args: [
+        {
+          name: "params";
+          type: {
+            defined: {
+              name: "createMarketParams";
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "createResolverCouncil";
+      docs: [
+        "Create an M-of-N resolver council"
+      ];
+      discriminator: [172, 77, 178, 190, 177, 255, 136, 42];
+      accounts: [
+        {
+          name: "authority";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "council";
+          writable: true;
+        },
+        {
+          name: "systemProgram";
+        }
+      ];
+      args: [
+        {
+          name: "councilId";
+          type: "u64";
+        },
+        {
+          name: "members";
+          type: {
+            vec: "pubkey";
+          };
+        },
+        {
+          name: "threshold";
+          type: "u8";
         }
       ];
-      args: [];
     },
     {
       name: "emergencyPause";
+      docs: [
+        "Emergency pause - only authority"
+      ];
+      discriminator: [21, 143, 27, 142, 200, 181, 210, 255];
       accounts: [
         {
           name: "authority";
-          isMut: false;
-          isSigner: true;
+          signer: true;
         },
         {
           name: "globalState";
-          isMut: true;
-          isSigner: false;
+          writable: true;
         }
       ];
       args: [];
     },
     {
       name: "emergencyUnpause";
+      docs: [
+        "Emergency unpause - only authority"
+      ];
+      discriminator: [83, 249, 195, 57, 206, 189, 31, 85];
       accounts: [
         {
           name: "authority";
-          isMut: false;
-          isSigner: true;
+          signer: true;
         },
         {
           name: "globalState";
-          isMut: true;
-          isSigner: false;
+          writable: true;
         }
       ];
       args: [];
     },
     {
-      name: "getMarketOdds";
+      name: "finalizeMarket";
+      docs: [
+        "Finalize a resolved market after its dispute window - permissionless"
+      ];
+      discriminator: [16, 225, 38, 28, 213, 217, 1, 247];
       accounts: [
+        {
+          name: "caller";
+          signer: true;
+        },
         {
           name: "market";
-          isMut: false;
-          isSigner: false;
+          writable: true;
         }
       ];
       args: [];
     },
     {
-      name: "getUserPosition";
+      name: "getMarketOdds";
+      docs: [
+        "View function: Get market odds for all outcomes"
+      ];
+      discriminator: [78, 57, 4, 185, 205, 84, 144, 119];
       accounts: [
         {
           name: "market";
-          isMut: false;
-          isSigner: false;
+        },
+        {
+          name: "outcomeBook";
         }
       ];
       args: [];
-    }
-  ];
-  accounts: [
+      returns: {
+        vec: {
+          defined: {
+            name: "outcomeOdds";
+          };
+        };
+      };
+    },
     {
-      name: "globalState";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "authority";
-            type: "publicKey";
-          },
+      name: "getReferrerCodeOwner";
+      docs: [
+        "View function: Resolve a referrer code to its owner"
+      ];
+      discriminator: [164, 135, 198, 92, 88, 148, 230, 103];
+      accounts: [
+        {
+          name: "referrerCodeAccount";
+        }
+      ];
+      args: [
+        {
+          name: "referrerCode";
+          type: "string";
+        }
+      ];
+      returns: {
+        defined: {
+          name: "referrerCodeOwner";
+        };
+      };
+    },
+    {
+      name: "getUserPosition";
+      docs: [
+        "View function: Get user position in market"
+      ];
+      discriminator: [134, 165, 10, 74, 46, 176, 252, 181];
+      accounts: [
+        {
+          name: "market";
+        },
+        {
+          name: "outcomeBook";
+        },
+        {
+          name: "user";
+        }
+      ];
+      args: [];
+      returns: {
+        vec: {
+          defined: {
+            name: "outcomePosition";
+          };
+        };
+      };
+    },
+    {
+      name: "initializeGlobal";
+      docs: [
+        "Initialize global program state"
+      ];
+      discriminator: [47, 225, 15, 112, 86, 51, 190, 231];
+      accounts: [
+        {
+          name: "authority";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "globalState";
+          writable: true;
+        },
+        {
+          name: "systemProgram";
+        }
+      ];
+      args: [
+        {
+          name: "platformWallet";
+          type: "pubkey";
+        }
+      ];
+    },
+    {
+      name: "initializeOutcomeMint";
+      docs: [
+        "Create the share token mint for one outcome"
+      ];
+      discriminator: [17, 129, 120, 1, 210, 162, 52, 140];
+      accounts: [
+        {
+          name: "payer";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "market";
+          writable: true;
+        },
+        {
+          name: "outcomeBook";
+          writable: true;
+        },
+        {
+          name: "outcomeMint";
+          writable: true;
+        },
+        {
+          name: "tokenProgram";
+        },
+        {
+          name: "systemProgram";
+        }
+      ];
+      args: [
+        {
+          name: "outcomeIndex";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "initializeUser";
+      docs: [
+        "Initialize user profile and register its unique referrer code"
+      ];
+      discriminator: [111, 17, 185, 250, 60, 122, 38, 254];
+      accounts: [
+        {
+          name: "user";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "userProfile";
+          writable: true;
+        },
+        {
+          name: "referrerCodeAccount";
+          docs: [
+            "Registry entry for the normalized referrer code"
+          ];
+          writable: true;
+        },
+        {
+          name: "systemProgram";
+        }
+      ];
+      args: [
+        {
+          name: "referrerCode";
+          type: "string";
+        }
+      ];
+    },
+    {
+      name: "matchOrders";
+      docs: [
+        "Match a crossing bid and ask"
+      ];
+      discriminator: [17, 1, 201, 93, 7, 51, 251, 134];
+      accounts: [
+        {
+          name: "matcher";
+          signer: true;
+        },
+        {
+          name: "bid";
+          writable: true;
+        },
+        {
+          name: "ask";
+          writable: true;
+        },
+        {
+          name: "outcomeMint";
+          writable: true;
+        },
+        {
+          name: "buyerOutcomeAccount";
+          writable: true;
+        },
+        {
+          name: "buyerTokenAccount";
+          writable: true;
+        },
+        {
+          name: "sellerTokenAccount";
+          writable: true;
+        },
+        {
+          name: "buyerCostBasis";
+          docs: [
+            "Created when the bid was placed"
+          ];
+          writable: true;
+        },
+        {
+          name: "sellerCostBasis";
+          docs: [
+            "Created when the ask was placed"
+          ];
+          writable: true;
+        },
+        {
+          name: "marketTokenAccount";
+          docs: [
+            "Market vault holding the collateral"
+          ];
+          writable: true;
+        },
+        {
+          name: "market";
+          writable: true;
+        },
+        {
+          name: "outcomeBook";
+          writable: true;
+        },
+        {
+          name: "marketVaultAuthority";
+          docs: [
+            "Market PDA used as authority for CPI"
+          ];
+        },
+        {
+          name: "globalState";
+        },
+        {
+          name: "tokenProgram";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "migrateGlobalState";
+      docs: [
+        "Grow the legacy global state to the current layout - only authority"
+      ];
+      discriminator: [252, 251, 45, 153, 73, 1, 168, 198];
+      accounts: [
+        {
+          name: "authority";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "globalState";
+          docs: [
+            "are verified in the handler"
+          ];
+          writable: true;
+        },
+        {
+          name: "systemProgram";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "migrateMarket";
+      docs: [
+        "Move a legacy market to its ID-seeded address and current layout - only authority"
+      ];
+      discriminator: [201, 113, 181, 120, 217, 60, 109, 203];
+      accounts: [
+        {
+          name: "authority";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "globalState";
+          writable: true;
+        },
+        {
+          name: "legacyMarket";
+          docs: [
+            "and address are verified in the handler"
+          ];
+          writable: true;
+        },
+        {
+          name: "market";
+          writable: true;
+        },
+        {
+          name: "outcomeBook";
+          docs: [
+            "Per-outcome state of the migrated market, allocated by the client (`OutcomeBook::LEN` bytes)"
+          ];
+          writable: true;
+        },
+        {
+          name: "collateralMint";
+          docs: [
+            "Mint the legacy market was denominated in; must be on the global allowlist"
+          ];
+        },
+        {
+          name: "legacyTokenAccount";
+          docs: [
+            "Token account holding the legacy market's collateral; closed by the migration"
+          ];
+          writable: true;
+        },
+        {
+          name: "marketTokenAccount";
+          docs: [
+            "Market vault holding the collateral, owned by the new market PDA"
+          ];
+          writable: true;
+        },
+        {
+          name: "authorityCostBasis";
+          docs: [
+            "The authority's cost basis in the migrated market, held for the original holders"
+          ];
+          writable: true;
+        },
+        {
+          name: "tokenProgram";
+        },
+        {
+          name: "associatedTokenProgram";
+        },
+        {
+          name: "systemProgram";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "mintCompleteSet";
+      docs: [
+        "Mint complete sets of outcome shares for USDC"
+      ];
+      discriminator: [70, 222, 130, 148, 234, 103, 137, 61];
+      accounts: [
+        {
+          name: "user";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "userTokenAccount";
+          writable: true;
+        },
+        {
+          name: "marketTokenAccount";
+          docs: [
+            "Market vault holding the collateral"
+          ];
+          writable: true;
+        },
+        {
+          name: "market";
+          writable: true;
+        },
+        {
+          name: "outcomeBook";
+          writable: true;
+        },
+        {
+          name: "globalState";
+        },
+        {
+          name: "userCostBasis";
+          docs: [
+            "The user's net cost in this market, refunded if it is voided"
+          ];
+          writable: true;
+        },
+        {
+          name: "tokenProgram";
+        },
+        {
+          name: "systemProgram";
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "placeOrder";
+      docs: [
+        "Place a limit order for outcome shares"
+      ];
+      discriminator: [51, 194, 155, 175, 109, 130, 96, 106];
+      accounts: [
+        {
+          name: "owner";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "ownerTokenAccount";
+          writable: true;
+        },
+        {
+          name: "marketTokenAccount";
+          docs: [
+            "Market vault holding the collateral"
+          ];
+          writable: true;
+        },
+        {
+          name: "market";
+          writable: true;
+        },
+        {
+          name: "outcomeBook";
+        },
+        {
+          name: "outcomeMint";
+          writable: true;
+        },
+        {
+          name: "ownerOutcomeAccount";
+          docs: [
+            "Created on first use so fills can be minted into it"
+          ];
+          writable: true;
+        },
+        {
+          name: "order";
+          writable: true;
+        },
+        {
+          name: "ownerCostBasis";
+          docs: [
+            "The owner's net cost in this market, refunded if it is voided"
+          ];
+          writable: true;
+        },
+        {
+          name: "globalState";
+        },
+        {
+          name: "tokenProgram";
+        },
+        {
+          name: "associatedTokenProgram";
+        },
+        {
+          name: "systemProgram";
+        }
+      ];
+      args: [
+        {
+          name: "outcomeIndex";
+          type: "u8";
+        },
+        {
+          name: "side";
+          type: {
+            defined: {
+              name: "orderSide";
+            };
+          };
+        },
+        {
+          name: "price";
+          type: "u64";
+        },
+        {
+          name: "quantity";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "proposeOutcome";
+      docs: [
+        "Propose an outcome with a bond once resolution time has passed - only markets resolved by the authority"
+      ];
+      discriminator: [147, 78, 55, 89, 179, 236, 26, 248];
+      accounts: [
+        {
+          name: "proposer";
+          signer: true;
+        },
+        {
+          name: "proposerTokenAccount";
+          writable: true;
+        },
+        {
+          name: "marketTokenAccount";
+          docs: [
+            "Market vault holding the collateral"
+          ];
+          writable: true;
+        },
+        {
+          name: "market";
+          writable: true;
+        },
+        {
+          name: "outcomeBook";
+          writable: true;
+        },
+        {
+          name: "globalState";
+        },
+        {
+          name: "tokenProgram";
+        }
+      ];
+      args: [
+        {
+          name: "payoutNumerators";
+          type: {
+            vec: "u32";
+          };
+        },
+        {
+          name: "payoutDenominator";
+          type: "u32";
+        }
+      ];
+    },
+    {
+      name: "redeemWinnings";
+      docs: [
+        "Redeem winnings from a resolved market"
+      ];
+      discriminator: [209, 5, 204, 87, 134, 122, 239, 185];
+      accounts: [
+        {
+          name: "winner";
+          signer: true;
+        },
+        {
+          name: "winnerTokenAccount";
+          writable: true;
+        },
+        {
+          name: "marketTokenAccount";
+          docs: [
+            "Market vault holding the collateral"
+          ];
+          writable: true;
+        },
+        {
+          name: "market";
+          writable: true;
+        },
+        {
+          name: "outcomeBook";
+          writable: true;
+        },
+        {
+          name: "marketVaultAuthority";
+          docs: [
+            "Market PDA used as authority for CPI"
+          ];
+        },
+        {
+          name: "globalState";
+        },
+        {
+          name: "outcomeMint";
+          writable: true;
+        },
+        {
+          name: "winnerOutcomeAccount";
+          writable: true;
+        },
+        {
+          name: "tokenProgram";
+        }
+      ];
+      args: [
+        {
+          name: "outcomeIndex";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "removeCollateralMint";
+      docs: [
+        "Remove a collateral mint from the allowlist - only authority"
+      ];
+      discriminator: [24, 175, 129, 176, 151, 91, 225, 4];
+      accounts: [
+        {
+          name: "authority";
+          signer: true;
+        },
+        {
+          name: "globalState";
+          writable: true;
+        }
+      ];
+      args: [
+        {
+          name: "mint";
+          type: "pubkey";
+        }
+      ];
+    },
+    {
+      name: "resolveDispute";
+      docs: [
+        "Confirm or overturn a challenged resolution - only arbiter"
+      ];
+      discriminator: [231, 6, 202, 6, 96, 103, 12, 230];
+      accounts: [
+        {
+          name: "arbiter";
+          signer: true;
+        },
+        {
+          name: "challengerTokenAccount";
+          writable: true;
+        },
+        {
+          name: "platformTokenAccount";
+          writable: true;
+        },
+        {
+          name: "marketTokenAccount";
+          docs: [
+            "Market vault holding the collateral"
+          ];
+          writable: true;
+        },
+        {
+          name: "market";
+          writable: true;
+        },
+        {
+          name: "outcomeBook";
+          writable: true;
+        },
+        {
+          name: "marketVaultAuthority";
+          docs: [
+            "Market PDA used as authority for CPI"
+          ];
+        },
+        {
+          name: "globalState";
+        },
+        {
+          name: "tokenProgram";
+        }
+      ];
+      args: [
+        {
+          name: "payoutNumerators";
+          type: {
+            option: {
+              vec: "u32";
+            };
+          };
+        },
+        {
+          name: "payoutDenominator";
+          type: "u32";
+        }
+      ];
+    },
+    {
+      name: "resolveFromAttestation";
+      docs: [
+        "Resolve a publisher market from a signed attestation - permissionless"
+      ];
+      discriminator: [200, 5, 83, 239, 186, 148, 146, 178];
+      accounts: [
+        {
+          name: "resolver";
+          signer: true;
+        },
+        {
+          name: "market";
+          writable: true;
+        },
+        {
+          name: "outcomeBook";
+          writable: true;
+        },
+        {
+          name: "instructionsSysvar";
+        },
+        {
+          name: "globalState";
+        }
+      ];
+      args: [
+        {
+          name: "winningOutcome";
+          type: "u8";
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+        }
+      ];
+    },
+    {
+      name: "resolveFromPriceFeed";
+      docs: [
+        "Resolve a price-band market from its Pyth feed - permissionless"
+      ];
+      discriminator: [183, 185, 130, 61, 103, 79, 119, 191];
+      accounts: [
+        {
+          name: "resolver";
+          signer: true;
+        },
+        {
+          name: "market";
+          writable: true;
+        },
+        {
+          name: "outcomeBook";
+          writable: true;
+        },
+        {
+          name: "priceFeed";
+        },
+        {
+          name: "globalState";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "resolveMarket";
+      docs: [
+        "Resolve a market with a payout vector - only the market's resolver"
+      ];
+      discriminator: [155, 23, 80, 173, 46, 74, 23, 239];
+      accounts: [
+        {
+          name: "resolver";
+          signer: true;
+        },
+        {
+          name: "market";
+          writable: true;
+        },
+        {
+          name: "outcomeBook";
+          writable: true;
+        },
+        {
+          name: "globalState";
+        }
+      ];
+      args: [
+        {
+          name: "payoutNumerators";
+          type: {
+            vec: "u32";
+          };
+        },
+        {
+          name: "payoutDenominator";
+          type: "u32";
+        }
+      ];
+    },
+    {
+      name: "sellOutcome";
+      docs: [
+        "Sell shares of an outcome"
+      ];
+      discriminator: [78, 3, 14, 78, 71, 181, 114, 71];
+      accounts: [
+        {
+          name: "seller";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "sellerTokenAccount";
+          writable: true;
+        },
+        {
+          name: "marketTokenAccount";
+          docs: [
+            "Market vault holding the collateral"
+          ];
+          writable: true;
+        },
+        {
+          name: "market";
+          docs: [
+            "Market PDA acts as vault authority for token transfers"
+          ];
+          writable: true;
+        },
+        {
+          name: "outcomeBook";
+          writable: true;
+        },
+        {
+          name: "marketVaultAuthority";
+          docs: [
+            "Market PDA used as authority for CPI"
+          ];
+        },
+        {
+          name: "globalState";
+        },
+        {
+          name: "outcomeMint";
+          writable: true;
+        },
+        {
+          name: "sellerOutcomeAccount";
+          writable: true;
+        },
+        {
+          name: "sellerCostBasis";
+          docs: [
+            "The seller's net cost in this market, refunded if it is voided"
+          ];
+          writable: true;
+        },
+        {
+          name: "tokenProgram";
+        },
+        {
+          name: "systemProgram";
+        }
+      ];
+      args: [
+        {
+          name: "outcomeIndex";
+          type: "u8";
+        },
+        {
+          name: "sharesToSell";
+          type: "u64";
+        },
+        {
+          name: "minAmountOut";
+          type: "u64";
+        },
+        {
+          name: "deadline";
+          type: {
+            option: "i64";
+          };
+        }
+      ];
+    },
+    {
+      name: "setDisputeConfig";
+      docs: [
+        "Configure the dispute window, bond and arbiter - only authority"
+      ];
+      discriminator: [140, 9, 117, 207, 15, 127, 158, 49];
+      accounts: [
+        {
+          name: "authority";
+          signer: true;
+        },
+        {
+          name: "globalState";
+          writable: true;
+        }
+      ];
+      args: [
+        {
+          name: "disputePeriodSecs";
+          type: "i64";
+        },
+        {
+          name: "disputeBond";
+          type: "u64";
+        },
+        {
+          name: "arbiter";
+          type: "pubkey";
+        }
+      ];
+    },
+    {
+      name: "setOutcomeLabels";
+      docs: [
+        "Set outcome labels in batches while the market is in draft - only creator"
+      ];
+      discriminator: [62, 211, 26, 234, 84, 40, 93, 53];
+      accounts: [
+        {
+          name: "creator";
+          signer: true;
+        },
+        {
+          name: "market";
+        },
+        {
+          name: "outcomeBook";
+          writable: true;
+        }
+      ];
+      args: [
+        {
+          name: "startIndex";
+          type: "u8";
+        },
+        {
+          name: "labels";
+          type: {
+            vec: "string";
+          };
+        }
+      ];
+    },
+    {
+      name: "settleProposal";
+      docs: [
+        "Settle an unchallenged proposal and reward its proposer"
+      ];
+      discriminator: [118, 224, 98, 222, 158, 20, 205, 190];
+      accounts: [
+        {
+          name: "settler";
+          signer: true;
+        },
+        {
+          name: "proposerTokenAccount";
+          writable: true;
+        },
+        {
+          name: "marketTokenAccount";
+          docs: [
+            "Market vault holding the collateral"
+          ];
+          writable: true;
+        },
+        {
+          name: "market";
+          writable: true;
+        },
+        {
+          name: "outcomeBook";
+          writable: true;
+        },
+        {
+          name: "marketVaultAuthority";
+          docs: [
+            "Market PDA used as authority for CPI"
+          ];
+        },
+        {
+          name: "globalState";
+        },
+        {
+          name: "tokenProgram";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "submitResolutionVote";
+      docs: [
+        "Vote on a market's outcome as a member of its resolver council"
+      ];
+      discriminator: [88, 224, 139, 77, 163, 1, 198, 160];
+      accounts: [
+        {
+          name: "member";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "council";
+        },
+        {
+          name: "market";
+          writable: true;
+        },
+        {
+          name: "outcomeBook";
+          writable: true;
+        },
+        {
+          name: "votes";
+          writable: true;
+        },
+        {
+          name: "globalState";
+        },
+        {
+          name: "systemProgram";
+        }
+      ];
+      args: [
+        {
+          name: "payoutNumerators";
+          type: {
+            vec: "u32";
+          };
+        },
+        {
+          name: "payoutDenominator";
+          type: "u32";
+        }
+      ];
+    },
+    {
+      name: "updateMarketTags";
+      docs: [
+        "Replace a market's tags - only creator"
+      ];
+      discriminator: [26, 215, 122, 16, 17, 84, 4, 52];
+      accounts: [
+        {
+          name: "creator";
+          writable: true;
+          signer: true;
+        },
+        {
+          name: "market";
+          writable: true;
+        },
+        {
+          name: "systemProgram";
+        }
+      ];
+      args: [
+        {
+          name: "tags";
+          type: {
+            vec: "string";
+          };
+        }
+      ];
+    },
+    {
+      name: "voidExpiredMarket";
+      docs: [
+        "Void a market left unresolved or still disputed past its deadline - permissionless"
+      ];
+      discriminator: [30, 94, 14, 144, 32, 213, 10, 204];
+      accounts: [
+        {
+          name: "caller";
+          signer: true;
+        },
+        {
+          name: "market";
+          writable: true;
+        },
+        {
+          name: "outcomeBook";
+          writable: true;
+        },
+        {
+          name: "proposerTokenAccount";
+          docs: [
+            "Proposer's collateral account; required while a proposal bond is posted"
+          ];
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "challengerTokenAccount";
+          docs: [
+            "Challenger's collateral account; required while a challenge bond is posted"
+          ];
+          writable: true;
+          optional: true;
+        },
+        {
+          name: "marketTokenAccount";
+          docs: [
+            "Market vault holding the collateral"
+          ];
+          writable: true;
+        },
+        {
+          name: "marketVaultAuthority";
+          docs: [
+            "Market PDA used as authority for CPI"
+          ];
+        },
+        {
+          name: "tokenProgram";
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "voidMarket";
+      docs: [
+        "Void a market instead of resolving it - only authority"
+      ];
+      discriminator: [243, 175, 46, 124, 95, 101, 39, 69];
+      accounts: [
+        {
+          name: "authority";
+          signer: true;
+        },
+        {
+          name: "platformTokenAccount";
+          writable: true;
+        },
+        {
+          name: "marketTokenAccount";
+          docs: [
+            "Market vault holding the collateral"
+          ];
+          writable: true;
+        },
+        {
+          name: "market";
+          writable: true;
+        },
+        {
+          name: "marketVaultAuthority";
+          docs: [
+            "Market PDA used as authority for CPI"
+          ];
+        },
+        {
+          name: "globalState";
+        },
+        {
+          name: "tokenProgram";
+        }
+      ];
+      args: [
+        {
+          name: "reason";
+          type: {
+            defined: {
+              name: "voidReason";
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "withdrawFees";
+      docs: [
+        "Withdraw accumulated trading fees"
+      ];
+      discriminator: [198, 212, 171, 109, 144, 215, 174, 89];
+      accounts: [
+        {
+          name: "caller";
+          signer: true;
+        },
+        {
+          name: "creatorTokenAccount";
+          writable: true;
+        },
+        {
+          name: "invitorTokenAccount";
+          docs: [
+            "Invitor's collateral account; unused when the market has no invitor"
+          ];
+          writable: true;
+        },
+        {
+          name: "platformTokenAccount";
+          writable: true;
+        },
+        {
+          name: "marketTokenAccount";
+          docs: [
+            "Market vault holding the collateral"
+          ];
+          writable: true;
+        },
+        {
+          name: "market";
+          writable: true;
+        },
+        {
+          name: "outcomeBook";
+          writable: true;
+        },
+        {
+          name: "marketVaultAuthority";
+          docs: [
+            "Market PDA used as authority for CPI"
+          ];
+        },
+        {
+          name: "globalState";
+        },
+        {
+          name: "tokenProgram";
+        }
+      ];
+      args: [];
+    }
+  ];
+  accounts: [
+    {
+      name: "costBasis";
+      discriminator: [187, 27, 160, 82, 140, 143, 99, 79];
+    },
+    {
+      name: "globalState";
+      discriminator: [163, 46, 74, 168, 216, 123, 133, 98];
+    },
+    {
+      name: "market";
+      discriminator: [219, 190, 213, 55, 0, 227, 198, 154];
+    },
+    {
+      name: "order";
+      discriminator: [134, 173, 223, 185, 77, 86, 28, 51];
+    },
+    {
+      name: "outcomeBook";
+      discriminator: [241, 146, 27, 72, 205, 207, 232, 69];
+    },
+    {
+      name: "referrerCode";
+      discriminator: [140, 225, 81, 13, 229, 81, 169, 210];
+    },
+    {
+      name: "resolutionVotes";
+      discriminator: [253, 154, 94, 178, 129, 113, 130, 11];
+    },
+    {
+      name: "resolverCouncil";
+      discriminator: [249, 240, 154, 8, 16, 241, 13, 188];
+    },
+    {
+      name: "userProfile";
+      discriminator: [32, 37, 119, 205, 179, 180, 13, 194];
+    }
+  ];
+  types: [
+    {
+      docs: [
+        "Event emitted when a collateral mint is allowed"
+      ];
+      name: "collateralMintAdded";
+      type: {
+        fields: [
+          {
+            name: "mint";
+            type: "pubkey";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      docs: [
+        "Event emitted when a collateral mint is removed from the allowlist"
+      ];
+      name: "collateralMintRemoved";
+      type: {
+        fields: [
+          {
+            name: "mint";
+            type: "pubkey";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      docs: [
+        "Event emitted when complete sets are burned"
+      ];
+      name: "completeSetBurned";
+      type: {
+        fields: [
+          {
+            name: "market";
+            type: "pubkey";
+          },
+          {
+            name: "user";
+            type: "pubkey";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      docs: [
+        "Event emitted when complete sets are minted"
+      ];
+      name: "completeSetMinted";
+      type: {
+        fields: [
+          {
+            name: "market";
+            type: "pubkey";
+          },
+          {
+            name: "user";
+            type: "pubkey";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      name: "costBasis";
+      docs: [
+        "Net collateral a holder has put into one market, refunded if the market is voided",
+        "",
+        "Counts what the holder paid into the market's pools (buys, complete-set",
+        "mints, filled bids) less what they took out (sells, complete-set burns,",
+        "filled asks). Trading fees are excluded, and the basis never goes below",
+        "zero: a holder who has taken out more than they put in is owed nothing."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "market";
+            docs: [
+              "Market the basis is held in"
+            ];
+            type: "pubkey";
+          },
+          {
+            name: "owner";
+            docs: [
+              "Holder the basis belongs to"
+            ];
+            type: "pubkey";
+          },
+          {
+            name: "netCost";
+            docs: [
+              "Net collateral paid in, in collateral base units"
+            ];
+            type: "u64";
+          },
+          {
+            name: "bump";
+            docs: [
+              "Bump seed for PDA derivation"
+            ];
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "councilVote";
+      docs: [
+        "A council member's vote on a market's payout vector"
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "member";
+            docs: [
+              "Voting member"
+            ];
+            type: "pubkey";
+          },
+          {
+            name: "payoutNumerators";
+            docs: [
+              "Proposed payout numerator of each outcome"
+            ];
+            type: {
+              vec: "u32";
+            };
+          },
+          {
+            name: "payoutDenominator";
+            docs: [
+              "Sum of the proposed numerators"
+            ];
+            type: "u32";
+          }
+        ];
+      };
+    },
+    {
+      name: "createMarketParams";
+      docs: [
+        "Parameters of a new market"
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "numOutcomes";
+            type: "u8";
+          },
+          {
+            name: "outcomeLabels";
+            docs: [
+              "Labels for the first outcomes; the rest are set with `set_outcome_labels`"
+            ];
+            type: {
+              vec: "string";
+            };
+          },
+          {
+            name: "tags";
+            type: {
+              vec: "string";
+            };
+          },
+          {
+            name: "tradingFeeBps";
+            type: "u16";
+          },
+          {
+            name: "resolveAt";
+            type: "i64";
+          },
+          {
+            name: "pricingMode";
+            type: {
+              defined: {
+                name: "pricingMode";
+              };
+            };
+          },
+          {
+            name: "liquidityParam";
+            docs: [
+              "LMSR `b` or CPMM seed liquidity in collateral base units; 0 for parimutuel"
+            ];
+            type: "u64";
+          },
+          {
+            name: "priceBands";
+            type: {
+              option: {
+                defined: {
+                  name: "priceBands";
+                };
+              };
+            };
+          },
+          {
+            name: "resolver";
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "publisher";
+            type: {
+              option: {
+                defined: {
+                  name: "dataPublisher";
+                };
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      docs: [
+        "Event emitted when creator claims their peg"
+      ];
+      name: "creatorPegClaimed";
+      type: {
+        fields: [
+          {
+            name: "market";
+            type: "pubkey";
+          },
+          {
+            name: "creator";
+            type: "pubkey";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      name: "dataPublisher";
+      docs: [
+        "Data vendor whose signed result resolves a market",
+        "",
+        "The publisher attests to a result by signing the 41-byte message",
+        "`market || outcome_index (u8) || timestamp (i64 LE)` with its ed25519 key."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "publisher";
+            docs: [
+              "Publisher's ed25519 signing key"
+            ];
+            type: "pubkey";
+          },
+          {
+            name: "eventId";
+            docs: [
+              "Publisher's identifier for the event (max 32 chars)"
+            ];
+            type: "string";
+          }
+        ];
+      };
+    },
+    {
+      docs: [
+        "Event emitted when the dispute configuration changes"
+      ];
+      name: "disputeConfigUpdated";
+      type: {
+        fields: [
+          {
+            name: "disputePeriodSecs";
+            type: "i64";
+          },
+          {
+            name: "disputeBond";
+            type: "u64";
+          },
+          {
+            name: "arbiter";
+            type: "pubkey";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      docs: [
+        "Event emitted when the arbiter rules on a challenged resolution"
+      ];
+      name: "disputeResolved";
+      type: {
+        fields: [
+          {
+            name: "market";
+            type: "pubkey";
+          },
+          {
+            name: "challenger";
+            type: "pubkey";
+          },
+          {
+            name: "overturned";
+            type: "bool";
+          },
+          {
+            name: "payoutNumerators";
+            type: {
+              vec: "u32";
+            };
+          },
+          {
+            name: "payoutDenominator";
+            type: "u32";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      docs: [
+        "Event emitted when fees are withdrawn"
+      ];
+      name: "feesWithdrawn";
+      type: {
+        fields: [
+          {
+            name: "market";
+            type: "pubkey";
+          },
+          {
+            name: "recipient";
+            type: "pubkey";
+          },
+          {
+            name: "totalAmount";
+            type: "u64";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      name: "globalState";
+      docs: [
+        "Global program state and configuration"
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "authority";
+            docs: [
+              "Program authority that can pause/unpause"
+            ];
+            type: "pubkey";
+          },
+          {
+            name: "platformWallet";
+            docs: [
+              "Wallet to collect platform fees"
+            ];
+            type: "pubkey";
+          },
+          {
+            name: "paused";
+            docs: [
+              "Whether the program is paused"
+            ];
+            type: "bool";
+          },
+          {
+            name: "collateralMints";
+            docs: [
+              "Mints markets may use as collateral"
+            ];
+            type: {
+              vec: "pubkey";
+            };
+          },
+          {
+            name: "disputePeriodSecs";
+            docs: [
+              "Seconds after resolution during which the outcome can be challenged"
+            ];
+            type: "i64";
+          },
+          {
+            name: "disputeBond";
+            docs: [
+              "Bond a challenger posts, in hundredths of a collateral token"
+            ];
+            type: "u64";
+          },
+          {
+            name: "arbiter";
+            docs: [
+              "Rules on challenged resolutions"
+            ];
+            type: "pubkey";
+          },
+          {
+            name: "marketCount";
+            docs: [
+              "Number of market IDs assigned so far; the next market gets `market_count + 1`"
+            ];
+            type: "u64";
+          },
+          {
+            name: "bump";
+            docs: [
+              "Bump seed for PDA derivation"
+            ];
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      docs: [
+        "Event emitted when global state is initialized"
+      ];
+      name: "globalStateInitialized";
+      type: {
+        fields: [
+          {
+            name: "authority";
+            type: "pubkey";
+          },
+          {
+            name: "platformWallet";
+            type: "pubkey";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      docs: [
+        "Event emitted when the global state moves to the current layout"
+      ];
+      name: "globalStateMigrated";
+      type: {
+        fields: [
+          {
+            name: "authority";
+            type: "pubkey";
+          },
+          {
+            name: "platformWallet";
+            type: "pubkey";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      name: "market";
+      docs: [
+        "Represents a single prediction market",
+        "Per-outcome state lives in the market's `OutcomeBook`"
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "marketId";
+            docs: [
+              "Sequential market ID from `GlobalState::market_count`, seeding the market PDA"
+            ];
+            type: "u64";
+          },
+          {
+            name: "legacyAddress";
+            docs: [
+              "Address the market had before it was migrated to its ID-seeded PDA;",
+              "orders placed before the migration stay derived from it"
+            ];
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "creator";
+            docs: [
+              "Market creator"
+            ];
+            type: "pubkey";
+          },
+          {
+            name: "invitor";
+            docs: [
+              "User who invited the creator"
+            ];
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "referrer";
+            docs: [
+              "User who referred the creator"
+            ];
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "collateralMint";
+            docs: [
+              "Token mint the market is denominated in"
+            ];
+            type: "pubkey";
+          },
+          {
+            name: "collateralDecimals";
+            docs: [
+              "Decimals of the collateral mint, shared by the outcome share mints"
+            ];
+            type: "u8";
+          },
+          {
+            name: "vault";
+            docs: [
+              "Token account holding the market's collateral, owned by the market PDA"
+            ];
+            type: "pubkey";
+          },
+          {
+            name: "outcomeBook";
+            docs: [
+              "Zero-copy account holding the market's per-outcome state"
+            ];
+            type: "pubkey";
+          },
+          {
+            name: "resolver";
+            docs: [
+              "Key allowed to resolve the market"
+            ];
+            type: "pubkey";
+          },
+          {
+            name: "numOutcomes";
+            docs: [
+              "Number of outcomes (2-200; at most 10 for LMSR and CPMM markets)"
+            ];
+            type: "u8";
+          },
+          {
+            name: "tags";
+            docs: [
+              "Market tags for filtering"
+            ];
+            type: {
+              vec: "string";
+            };
+          },
+          {
+            name: "priceBands";
+            docs: [
+              "Price bands for markets resolved from a Pyth feed (None for other markets)"
+            ];
+            type: {
+              option: {
+                defined: {
+                  name: "priceBands";
+                };
+              };
+            };
+          },
+          {
+            name: "publisher";
+            docs: [
+              "Data publisher for markets resolved from signed attestations (None for other markets)"
+            ];
+            type: {
+              option: {
+                defined: {
+                  name: "dataPublisher";
+                };
+              };
+            };
+          },
+          {
+            name: "tradingFeeBps";
+            docs: [
+              "Trading fee in basis points (1-500)"
+            ];
+            type: "u16";
+          },
+          {
+            name: "pricingMode";
+            docs: [
+              "Pricing mechanism used for buys and sells"
+            ];
+            type: {
+              defined: {
+                name: "pricingMode";
+              };
+            };
+          },
+          {
+            name: "liquidityParam";
+            docs: [
+              "LMSR liquidity parameter `b`, or the initial CPMM liquidity (0 for parimutuel markets)"
+            ];
+            type: "u64";
+          },
+          {
+            name: "liquidityAmount";
+            docs: [
+              "Creator-funded liquidity still held by the market vault"
+            ];
+            type: "u64";
+          },
+          {
+            name: "resolveAt";
+            docs: [
+              "Timestamp when market resolves"
+            ];
+            type: "i64";
+          },
+          {
+            name: "resolutionDeadline";
+            docs: [
+              "Timestamp after which an unresolved market can be voided by anyone"
+            ];
+            type: "i64";
+          },
+          {
+            name: "status";
+            docs: [
+              "Where the market is in its lifecycle"
+            ];
+            type: {
+              defined: {
+                name: "marketStatus";
+              };
+            };
+          },
+          {
+            name: "payoutDenominator";
+            docs: [
+              "Denominator of the payout numerators in the outcome book (0 until resolution)"
+            ];
+            type: "u32";
+          },
+          {
+            name: "disputeEndsAt";
+            docs: [
+              "End of the window in which the resolution can be challenged"
+            ];
+            type: "i64";
+          },
+          {
+            name: "attestedAt";
+            docs: [
+              "Timestamp of the publisher attestation the market is resolved from (0 until then)"
+            ];
+            type: "i64";
+          },
+          {
+            name: "challenger";
+            docs: [
+              "User who challenged the resolution, while the challenge awaits the arbiter"
+            ];
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "challengeBond";
+            docs: [
+              "Bond posted by the challenger and held in the vault"
+            ];
+            type: "u64";
+          },
+          {
+            name: "proposer";
+            docs: [
+              "User who proposed the resolution, until the proposal is settled"
+            ];
+            type: {
+              option: "pubkey";
+            };
+          },
+          {
+            name: "proposalBond";
+            docs: [
+              "Bond posted by the proposer and held in the vault"
+            ];
+            type: "u64";
+          },
+          {
+            name: "creatorPegAmount";
+            docs: [
+              "Creator peg amount (in collateral base units)"
+            ];
+            type: "u64";
+          },
+          {
+            name: "creatorPegClaimed";
+            docs: [
+              "Whether creator has claimed their peg"
+            ];
+            type: "bool";
+          },
+          {
+            name: "voidReason";
+            docs: [
+              "Why the market was voided (only set for voided markets)"
+            ];
+            type: {
+              option: {
+                defined: {
+                  name: "voidReason";
+                };
+              };
+            };
+          },
+          {
+            name: "nextOrderId";
+            docs: [
+              "Id assigned to the next limit order"
+            ];
+            type: "u64";
+          },
+          {
+            name: "orderEscrow";
+            docs: [
+              "Collateral escrowed in the vault by open bids"
+            ];
+            type: "u64";
+          },
+          {
+            name: "askEscrow";
+            docs: [
+              "Outcome shares escrowed by open asks, burned until filled or cancelled"
+            ];
+            type: "u64";
+          },
+          {
+            name: "totalCostBasis";
+            docs: [
+              "Sum of every holder's `CostBasis::net_cost`, refunded if the market is voided"
+            ];
+            type: "u64";
+          },
+          {
+            name: "createdAt";
+            docs: [
+              "Market creation timestamp"
+            ];
+            type: "i64";
+          },
+          {
+            name: "bump";
+            docs: [
+              "Bump seed for PDA derivation"
+            ];
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      docs: [
+        "Event emitted when a settled market and its vault are closed"
+      ];
+      name: "marketClosed";
+      type: {
+        fields: [
+          {
+            name: "market";
+            type: "pubkey";
+          },
+          {
+            name: "creator";
+            type: "pubkey";
+          },
+          {
+            name: "dustSwept";
+            type: "u64";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      docs: [
+        "Event emitted when a market is created"
+      ];
+      name: "marketCreated";
+      type: {
+        fields: [
+          {
+            name: "market";
+            type: "pubkey";
+          },
+          {
+            name: "marketId";
+            type: "u64";
+          },
+          {
+            name: "creator";
+            type: "pubkey";
+          },
+          {
+            name: "collateralMint";
+            type: "pubkey";
+          },
+          {
+            name: "resolver";
+            type: "pubkey";
+          },
+          {
+            name: "numOutcomes";
+            type: "u8";
+          },
+          {
+            name: "tradingFeeBps";
+            type: "u16";
+          },
+          {
+            name: "resolveAt";
+            type: "i64";
+          },
+          {
+            name: "pricingMode";
+            type: {
+              defined: {
+                name: "pricingMode";
+              };
+            };
+          },
+          {
+            name: "liquidityParam";
+            type: "u64";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      docs: [
+        "Event emitted when a legacy market moves to its ID-seeded address"
+      ];
+      name: "marketMigrated";
+      type: {
+        fields: [
+          {
+            name: "legacyMarket";
+            type: "pubkey";
+          },
+          {
+            name: "market";
+            type: "pubkey";
+          },
+          {
+            name: "marketId";
+            type: "u64";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      docs: [
+        "Event emitted when a market is resolved"
+      ];
+      name: "marketResolved";
+      type: {
+        fields: [
+          {
+            name: "market";
+            type: "pubkey";
+          },
+          {
+            name: "payoutNumerators";
+            type: {
+              vec: "u32";
+            };
+          },
+          {
+            name: "payoutDenominator";
+            type: "u32";
+          },
+          {
+            name: "resolvedAt";
+            type: "i64";
+          },
+          {
+            name: "disputeEndsAt";
+            type: "i64";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      docs: [
+        "Event emitted when a market is resolved from its publisher's attestation"
+      ];
+      name: "marketResolvedFromAttestation";
+      type: {
+        fields: [
+          {
+            name: "market";
+            type: "pubkey";
+          },
+          {
+            name: "publisher";
+            type: "pubkey";
+          },
+          {
+            name: "eventId";
+            type: "string";
+          },
+          {
+            name: "winningOutcome";
+            type: "u8";
+          },
+          {
+            name: "attestedAt";
+            type: "i64";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      docs: [
+        "Event emitted when a price-band market is resolved from its feed"
+      ];
+      name: "marketResolvedFromPrice";
+      type: {
+        fields: [
+          {
+            name: "market";
+            type: "pubkey";
+          },
+          {
+            name: "priceFeed";
+            type: "pubkey";
+          },
+          {
+            name: "price";
+            type: "i64";
+          },
+          {
+            name: "expo";
+            type: "i32";
+          },
+          {
+            name: "publishTime";
+            type: "i64";
+          },
+          {
+            name: "winningOutcome";
+            type: "u8";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      docs: [
+        "Lifecycle of a market"
+      ];
+      name: "marketStatus";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "draft";
+          },
+          {
+            name: "open";
+          },
+          {
+            name: "closed";
+          },
+          {
+            name: "proposed";
+          },
+          {
+            name: "resolved";
+          },
+          {
+            name: "disputed";
+          },
+          {
+            name: "voided";
+          },
+          {
+            name: "finalized";
+          }
+        ];
+      };
+    },
+    {
+      name: "marketStatusChanged";
+      type: {
+        fields: [
+          {
+            name: "market";
+            type: "pubkey";
+          },
+          {
+            name: "previous";
+            type: {
+              defined: {
+                name: "marketStatus";
+              };
+            };
+          },
+          {
+            name: "status";
+            type: {
+              defined: {
+                name: "marketStatus";
+              };
+            };
+          },
+          {
+            name: "changedAt";
+            type: "i64";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      docs: [
+        "Event emitted when a market's tags are replaced"
+      ];
+      name: "marketTagsUpdated";
+      type: {
+        fields: [
+          {
+            name: "market";
+            type: "pubkey";
+          },
+          {
+            name: "tags";
+            type: {
+              vec: "string";
+            };
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      docs: [
+        "Event emitted when a market is voided"
+      ];
+      name: "marketVoided";
+      type: {
+        fields: [
+          {
+            name: "market";
+            type: "pubkey";
+          },
+          {
+            name: "reason";
+            type: {
+              defined: {
+                name: "voidReason";
+              };
+            };
+          },
+          {
+            name: "pegForfeited";
+            type: "u64";
+          },
+          {
+            name: "voidedAt";
+            type: "i64";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      name: "order";
+      docs: [
+        "A resting limit order for shares of one outcome"
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "market";
+            docs: [
+              "Market this order trades in (its pre-migration address for migrated markets)"
+            ];
+            type: "pubkey";
+          },
+          {
+            name: "owner";
+            docs: [
+              "Order owner"
+            ];
+            type: "pubkey";
+          },
+          {
+            name: "orderId";
+            docs: [
+              "Sequential order id within the market (lower ids rest first)"
+            ];
+            type: "u64";
+          },
+          {
+            name: "outcomeIndex";
+            docs: [
+              "Outcome being traded"
+            ];
+            type: "u8";
+          },
+          {
+            name: "side";
+            docs: [
+              "Bid or ask"
+            ];
+            type: {
+              defined: {
+                name: "orderSide";
+              };
+            };
+          },
+          {
+            name: "price";
+            docs: [
+              "Limit price in collateral per share, scaled by `ORDER_PRICE_SCALE`"
+            ];
+            type: "u64";
+          },
+          {
+            name: "quantity";
+            docs: [
+              "Original order size in shares"
+            ];
+            type: "u64";
+          },
+          {
+            name: "remaining";
+            docs: [
+              "Shares not yet filled"
+            ];
+            type: "u64";
+          },
+          {
+            name: "escrow";
+            docs: [
+              "Collateral (bids) or shares (asks) still held in escrow"
+            ];
+            type: "u64";
+          },
+          {
+            name: "createdAt";
+            docs: [
+              "Order placement timestamp"
+            ];
+            type: "i64";
+          },
+          {
+            name: "bump";
+            docs: [
+              "Bump seed for PDA derivation"
+            ];
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      docs: [
+        "Event emitted when a limit order is cancelled"
+      ];
+      name: "orderCancelled";
+      type: {
+        fields: [
+          {
+            name: "market";
+            type: "pubkey";
+          },
+          {
+            name: "owner";
+            type: "pubkey";
+          },
+          {
+            name: "orderId";
+            type: "u64";
+          },
+          {
+            name: "unfilledQuantity";
+            type: "u64";
+          },
+          {
+            name: "escrowReturned";
+            type: "u64";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      docs: [
+        "Event emitted when a limit order is placed"
+      ];
+      name: "orderPlaced";
+      type: {
+        fields: [
+          {
+            name: "market";
+            type: "pubkey";
+          },
+          {
+            name: "owner";
+            type: "pubkey";
+          },
+          {
+            name: "orderId";
+            type: "u64";
+          },
+          {
+            name: "outcomeIndex";
+            type: "u8";
+          },
+          {
+            name: "side";
+            type: {
+              defined: {
+                name: "orderSide";
+              };
+            };
+          },
+          {
+            name: "price";
+            type: "u64";
+          },
+          {
+            name: "quantity";
+            type: "u64";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      docs: [
+        "Side of a limit order"
+      ];
+      name: "orderSide";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "bid";
+          },
+          {
+            name: "ask";
+          }
+        ];
+      };
+    },
+    {
+      docs: [
+        "Event emitted when a bid and an ask are matched"
+      ];
+      name: "ordersMatched";
+      type: {
+        fields: [
+          {
+            name: "market";
+            type: "pubkey";
+          },
+          {
+            name: "outcomeIndex";
+            type: "u8";
+          },
+          {
+            name: "bidId";
+            type: "u64";
+          },
+          {
+            name: "askId";
+            type: "u64";
+          },
+          {
+            name: "buyer";
+            type: "pubkey";
+          },
+          {
+            name: "seller";
+            type: "pubkey";
+          },
+          {
+            name: "price";
+            type: "u64";
+          },
+          {
+            name: "quantity";
+            type: "u64";
+          },
+          {
+            name: "feeAmount";
+            type: "u64";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      name: "outcomeBook";
+      docs: [
+        "Per-outcome state of a market, kept zero-copy in its own account",
+        "",
+        "Trades borrow the account in place instead of deserializing every outcome,",
+        "so a parimutuel trade touches only the entries of the outcome it trades.",
+        "Arrays are sized for `MAX_OUTCOMES`; only the first `num_outcomes` entries",
+        "are used. The account is larger than a program can create, so the client",
+        "allocates it (owned by this program, `OutcomeBook::LEN` bytes) in the same",
+        "transaction as `create_market`."
+      ];
+      serialization: "bytemuck";
+      repr: {
+        kind: "c";
+      };
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "market";
+            docs: [
+              "Market this book belongs to"
+            ];
+            type: "pubkey";
+          },
+          {
+            name: "outcomePools";
+            docs: [
+              "Total amount wagered on each outcome"
+            ];
+            type: {
+              array: [
+                "u64",
+                200
+              ];
+            };
+          },
+          {
+            name: "outcomeShares";
+            docs: [
+              "Number of shares issued for each outcome"
+            ];
+            type: {
+              array: [
+                "u64",
+                200
+              ];
+            };
+          },
+          {
+            name: "ammBalances";
+            docs: [
+              "Outcome shares held by the CPMM pool (unused for other pricing modes)"
+            ];
+            type: {
+              array: [
+                "u64",
+                200
+              ];
+            };
+          },
+          {
+            name: "payoutPools";
+            docs: [
+              "Collateral reserved at resolution for each outcome's outstanding shares"
+            ];
+            type: {
+              array: [
+                "u64",
+                200
+              ];
+            };
+          },
+          {
+            name: "accumulatedFees";
+            docs: [
+              "Accumulated trading fees per outcome (not yet withdrawn)"
+            ];
+            type: {
+              array: [
+                "u64",
+                200
+              ];
+            };
+          },
+          {
+            name: "outcomeMints";
+            docs: [
+              "SPL mint of each outcome's share token (default key until initialized)"
+            ];
+            type: {
+              array: [
+                "pubkey",
+                200
+              ];
+            };
+          },
+          {
+            name: "outcomeLabels";
+            docs: [
+              "UTF-8 label of each outcome, zero-padded"
+            ];
+            type: {
+              array: [
+                {
+                  array: [
+                    "u8",
+                    20
+                  ];
+                },
+                200
+              ];
+            };
+          },
+          {
+            name: "payoutNumerators";
+            docs: [
+              "Share of the payout each outcome receives, over the market's `payout_denominator`"
+            ];
+            type: {
+              array: [
+                "u32",
+                200
+              ];
+            };
+          },
+          {
+            name: "numOutcomes";
+            docs: [
+              "Number of outcomes in use"
+            ];
+            type: "u16";
+          },
+          {
+            name: "padding";
+            type: {
+              array: [
+                "u8",
+                6
+              ];
+            };
+          }
+        ];
+      };
+    },
+    {
+      docs: [
+        "Event emitted when outcome labels are set"
+      ];
+      name: "outcomeLabelsSet";
+      type: {
+        fields: [
+          {
+            name: "market";
+            type: "pubkey";
+          },
+          {
+            name: "startIndex";
+            type: "u8";
+          },
+          {
+            name: "labels";
+            type: {
+              vec: "string";
+            };
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      docs: [
+        "Event emitted when an outcome share mint is created"
+      ];
+      name: "outcomeMintInitialized";
+      type: {
+        fields: [
+          {
+            name: "market";
+            type: "pubkey";
+          },
+          {
+            name: "outcomeIndex";
+            type: "u8";
+          },
+          {
+            name: "mint";
+            type: "pubkey";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      name: "outcomeOdds";
+      docs: [
+        "View function to get current odds for an outcome"
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "outcomeIndex";
+            type: "u8";
+          },
+          {
+            name: "oddsBps";
+            type: "u64";
+          },
+          {
+            name: "outcomeLabel";
+            type: "string";
+          }
+        ];
+      };
+    },
+    {
+      name: "outcomePosition";
+      docs: [
+        "View function to get user position in a market outcome"
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "outcomeIndex";
+            type: "u8";
+          },
+          {
+            name: "sharesOwned";
+            type: "u64";
+          },
+          {
+            name: "currentValue";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      docs: [
+        "Event emitted when an outcome is proposed"
+      ];
+      name: "outcomeProposed";
+      type: {
+        fields: [
+          {
+            name: "market";
+            type: "pubkey";
+          },
+          {
+            name: "proposer";
+            type: "pubkey";
+          },
+          {
+            name: "payoutNumerators";
+            type: {
+              vec: "u32";
+            };
+          },
+          {
+            name: "payoutDenominator";
+            type: "u32";
+          },
+          {
+            name: "bond";
+            type: "u64";
+          },
+          {
+            name: "livenessEndsAt";
+            type: "i64";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      name: "priceBands";
+      docs: [
+        "Price bands of a market resolved from a Pyth price feed",
+        "",
+        "Outcome `i` wins when the feed's price lies in `[thresholds[i - 1], thresholds[i])`,",
+        "with the first and last bands open-ended, so `n` outcomes need `n - 1`",
+        "ascending thresholds. Thresholds are integers scaled by `10^expo`."
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "feed";
+            docs: [
+              "Pyth price account the market resolves from"
+            ];
+            type: "pubkey";
+          },
+          {
+            name: "expo";
+            docs: [
+              "Decimal exponent of the thresholds"
+            ];
+            type: "i32";
+          },
+          {
+            name: "thresholds";
+            docs: [
+              "Ascending band boundaries"
+            ];
+            type: {
+              vec: "i64";
+            };
+          }
+        ];
+      };
+    },
+    {
+      docs: [
+        "How a market prices outcome shares"
+      ];
+      name: "pricingMode";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "parimutuel";
+          },
+          {
+            name: "lmsr";
+          },
+          {
+            name: "cpmm";
+          }
+        ];
+      };
+    },
+    {
+      docs: [
+        "Event emitted when program is paused"
+      ];
+      name: "programPaused";
+      type: {
+        fields: [
+          {
+            name: "pausedAt";
+            type: "i64";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      docs: [
+        "Event emitted when program is unpaused"
+      ];
+      name: "programUnpaused";
+      type: {
+        fields: [
+          {
+            name: "unpausedAt";
+            type: "i64";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      docs: [
+        "Event emitted when a proposed outcome is settled"
+      ];
+      name: "proposalSettled";
+      type: {
+        fields: [
+          {
+            name: "market";
+            type: "pubkey";
+          },
+          {
+            name: "proposer";
+            type: "pubkey";
+          },
+          {
+            name: "bondReturned";
+            type: "u64";
+          },
+          {
+            name: "reward";
+            type: "u64";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      name: "referrerCode";
+      docs: [
+        "Registry entry mapping a normalized referrer code to the user who owns it"
+      ];
+      type: {
+        kind: "struct";
+        fields: [
           {
-            name: "platformWallet";
-            type: "publicKey";
+            name: "code";
+            docs: [
+              "Normalized code, also used as the PDA seed"
+            ];
+            type: "string";
           },
           {
-            name: "paused";
-            type: "bool";
+            name: "owner";
+            docs: [
+              "User who owns the code"
+            ];
+            type: "pubkey";
+          },
+          {
+            name: "profile";
+            docs: [
+              "Profile of the owner"
+            ];
+            type: "pubkey";
           },
           {
             name: "bump";
+            docs: [
+              "Bump seed for PDA derivation"
+            ];
             type: "u8";
           }
         ];
       };
     },
     {
-      name: "userProfile";
+      docs: [
+        "Event emitted when an existing profile's referrer code is registered"
+      ];
+      name: "referrerCodeBackfilled";
+      type: {
+        fields: [
+          {
+            name: "user";
+            type: "pubkey";
+          },
+          {
+            name: "referrerCode";
+            type: "string";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      name: "referrerCodeOwner";
+      docs: [
+        "View function to resolve a referrer code to its owner"
+      ];
       type: {
         kind: "struct";
         fields: [
+          {
+            name: "referrerCode";
+            type: "string";
+          },
           {
             name: "owner";
-            type: "publicKey";
+            type: "pubkey";
           },
           {
-            name: "invitor";
+            name: "profile";
+            type: "pubkey";
+          }
+        ];
+      };
+    },
+    {
+      docs: [
+        "Event emitted when a holder is refunded from a voided market"
+      ];
+      name: "refundClaimed";
+      type: {
+        fields: [
+          {
+            name: "market";
+            type: "pubkey";
+          },
+          {
+            name: "holder";
+            type: "pubkey";
+          },
+          {
+            name: "netCost";
+            type: "u64";
+          },
+          {
+            name: "amountRefunded";
+            type: "u64";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      docs: [
+        "Event emitted when a resolution is challenged"
+      ];
+      name: "resolutionChallenged";
+      type: {
+        fields: [
+          {
+            name: "market";
+            type: "pubkey";
+          },
+          {
+            name: "challenger";
+            type: "pubkey";
+          },
+          {
+            name: "bond";
+            type: "u64";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      docs: [
+        "Event emitted when a vote disagrees with one already cast"
+      ];
+      name: "resolutionVoteMismatch";
+      type: {
+        fields: [
+          {
+            name: "market";
+            type: "pubkey";
+          },
+          {
+            name: "council";
+            type: "pubkey";
+          },
+          {
+            name: "member";
+            type: "pubkey";
+          },
+          {
+            name: "conflictingMember";
+            type: "pubkey";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      docs: [
+        "Event emitted for every council vote"
+      ];
+      name: "resolutionVoteSubmitted";
+      type: {
+        fields: [
+          {
+            name: "market";
+            type: "pubkey";
+          },
+          {
+            name: "council";
+            type: "pubkey";
+          },
+          {
+            name: "member";
+            type: "pubkey";
+          },
+          {
+            name: "payoutNumerators";
             type: {
-              option: "publicKey";
+              vec: "u32";
             };
           },
           {
-            name: "referrerCode";
-            type: "string";
+            name: "payoutDenominator";
+            type: "u32";
           },
           {
-            name: "bump";
+            name: "matchingVotes";
             type: "u8";
           }
         ];
+        kind: "struct";
       };
     },
     {
-      name: "market";
+      name: "resolutionVotes";
+      docs: [
+        "Council votes cast on one market's resolution"
+      ];
       type: {
         kind: "struct";
         fields: [
           {
-            name: "creator";
-            type: "publicKey";
+            name: "market";
+            docs: [
+              "Market being resolved"
+            ];
+            type: "pubkey";
           },
           {
-            name: "invitor";
-            type: {
-              option: "publicKey";
-            };
+            name: "council";
+            docs: [
+              "Council voting on it"
+            ];
+            type: "pubkey";
           },
           {
-            name: "referrer";
+            name: "votes";
+            docs: [
+              "Latest vote of each member that has voted"
+            ];
             type: {
-              option: "publicKey";
+              vec: {
+                defined: {
+                  name: "councilVote";
+                };
+              };
             };
           },
           {
-            name: "numOutcomes";
+            name: "mismatches";
+            docs: [
+              "Number of votes cast that disagreed with an earlier vote"
+            ];
+            type: "u32";
+          },
+          {
+            name: "bump";
+            docs: [
+              "Bump seed for PDA derivation"
+            ];
             type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "resolverCouncil";
+      docs: [
+        "M-of-N council that can be named as a market's resolver"
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "authority";
+            docs: [
+              "Key that created the council"
+            ];
+            type: "pubkey";
           },
           {
-            name: "outcomeLabels";
-            type: {
-              vec: "string";
-            };
+            name: "councilId";
+            docs: [
+              "Id distinguishing the authority's councils"
+            ];
+            type: "u64";
           },
           {
-            name: "outcomePools";
+            name: "members";
+            docs: [
+              "Keys allowed to vote on resolutions"
+            ];
             type: {
-              vec: "u64";
+              vec: "pubkey";
             };
           },
           {
-            name: "outcomeShares";
-            type: {
-              vec: "u64";
-            };
+            name: "threshold";
+            docs: [
+              "Matching votes needed to resolve a market"
+            ];
+            type: "u8";
           },
           {
-            name: "tags";
+            name: "bump";
+            docs: [
+              "Bump seed for PDA derivation"
+            ];
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      docs: [
+        "Event emitted when a resolver council is created"
+      ];
+      name: "resolverCouncilCreated";
+      type: {
+        fields: [
+          {
+            name: "council";
+            type: "pubkey";
+          },
+          {
+            name: "authority";
+            type: "pubkey";
+          },
+          {
+            name: "members";
             type: {
-              vec: "string";
+              vec: "pubkey";
             };
           },
           {
-            name: "tradingFeeBps";
-            type: "u16";
+            name: "threshold";
+            type: "u8";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      docs: [
+        "Event emitted when shares are bought"
+      ];
+      name: "sharesBought";
+      type: {
+        fields: [
+          {
+            name: "market";
+            type: "pubkey";
           },
           {
-            name: "resolveAt";
-            type: "i64";
+            name: "buyer";
+            type: "pubkey";
           },
           {
-            name: "resolved";
-            type: "bool";
+            name: "outcomeIndex";
+            type: "u8";
           },
           {
-            name: "winningOutcome";
-            type: {
-              option: "u8";
-            };
+            name: "amountPaid";
+            type: "u64";
           },
           {
-            name: "creatorPegAmount";
+            name: "sharesReceived";
             type: "u64";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      docs: [
+        "Event emitted when shares are sold"
+      ];
+      name: "sharesSold";
+      type: {
+        fields: [
+          {
+            name: "market";
+            type: "pubkey";
           },
           {
-            name: "creatorPegClaimed";
-            type: "bool";
+            name: "seller";
+            type: "pubkey";
           },
           {
-            name: "accumulatedFees";
+            name: "outcomeIndex";
+            type: "u8";
+          },
+          {
+            name: "sharesSold";
+            type: "u64";
+          },
+          {
+            name: "amountReceived";
+            type: "u64";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      name: "userProfile";
+      docs: [
+        "User profile tracking invitor and referrer relationships"
+      ];
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "owner";
+            docs: [
+              "User's public key"
+            ];
+            type: "pubkey";
+          },
+          {
+            name: "invitor";
+            docs: [
+              "One-time binding to invitor (cannot be changed)"
+            ];
             type: {
-              vec: "u64";
+              option: "pubkey";
             };
           },
           {
-            name: "createdAt";
-            type: "i64";
+            name: "referrerCode";
+            docs: [
+              "Normalized referrer code given to others, registered in its `ReferrerCode` PDA"
+            ];
+            type: "string";
           },
           {
             name: "bump";
+            docs: [
+              "Bump seed for PDA derivation"
+            ];
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      docs: [
+        "Event emitted when a user profile is initialized"
+      ];
+      name: "userProfileInitialized";
+      type: {
+        fields: [
+          {
+            name: "user";
+            type: "pubkey";
+          },
+          {
+            name: "referrerCode";
+            type: "string";
+          }
+        ];
+        kind: "struct";
+      };
+    },
+    {
+      docs: [
+        "Why a market was voided instead of resolved"
+      ];
+      name: "voidReason";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "ambiguous";
+          },
+          {
+            name: "eventCancelled";
+          },
+          {
+            name: "delisted";
+          },
+          {
+            name: "resolutionExpired";
+          }
+        ];
+      };
+    },
+    {
+      docs: [
+        "Event emitted when a user redeems winnings"
+      ];
+      name: "winningsRedeemed";
+      type: {
+        fields: [
+          {
+            name: "market";
+            type: "pubkey";
+          },
+          {
+            name: "winner";
+            type: "pubkey";
+          },
+          {
+            name: "outcomeIndex";
             type: "u8";
+          },
+          {
+            name: "amountRedeemed";
+            type: "u64";
           }
         ];
+        kind: "struct";
       };
     }
   ];
   events: [
     {
-      name: "GlobalStateInitialized";
-      fields: [
-        {
-          name: "authority";
-          type: "publicKey";
-          index: false;
-        },
-        {
-          name: "platformWallet";
-          type: "publicKey";
-          index: false;
-        }
-      ];
+      discriminator: [212, 208, 223, 200, 254, 154, 114, 48];
+      name: "collateralMintAdded";
     },
     {
-      name: "UserProfileInitialized";
-      fields: [
-        {
-          name: "user";
-          type: "publicKey";
-          index: false;
-        },
-        {
-          name: "referrerCode";
-          type: "string";
-          index: false;
-        }
-      ];
+      discriminator: [12, 174, 11, 121, 254, 48, 106, 36];
+      name: "collateralMintRemoved";
     },
     {
-      name: "MarketCreated";
-      fields: [
-        {
-          name: "market";
-          type: "publicKey";
-          index: false;
-        },
-        {
-          name: "creator";
-          type: "publicKey";
-          index: false;
-        },
-        {
-          name: "numOutcomes";
-          type: "u8";
-          index: false;
-        },
-        {
-          name: "tradingFeeBps";
-          type: "u16";
-          index: false;
-        },
-        {
-          name: "resolveAt";
-          type: "i64";
-          index: false;
-        }
-      ];
+      discriminator: [202, 191, 88, 162, 119, 157, 167, 98];
+      name: "completeSetBurned";
     },
     {
-      name: "SharesBought";
-      fields: [
-        {
-          name: "market";
-          type: "publicKey";
-          index: false;
-        },
-        {
-          name: "buyer";
-          type: "publicKey";
-          index: false;
-        },
-        {
-          name: "outcomeIndex";
-          type: "u8";
-          index: false;
-        },
-        {
-          name: "amountPaid";
-          type: "u64";
-          index: false;
-        },
-        {
-          name: "sharesReceived";
-          type: "u64";
-          index: false;
-        }
-      ];
+      discriminator: [138, 100, 135, 145, 242, 178, 224, 155];
+      name: "completeSetMinted";
     },
     {
-      name: "SharesSold";
-      fields: [
-        {
-          name: "market";
-          type: "publicKey";
-          index: false;
-        },
-        {
-          name: "seller";
-          type: "publicKey";
-          index: false;
-        },
-        {
-          name: "outcomeIndex";
-          type: "u8";
-          index: false;
-        },
-        {
-          name: "sharesSold";
-          type: "u64";
-          index: false;
-        },
-        {
-          name: "amountReceived";
-          type: "u64";
-          index: false;
-        }
-      ];
+      discriminator: [28, 31, 2, 3, 240, 142, 48, 171];
+      name: "creatorPegClaimed";
     },
     {
-      name: "MarketResolved";
-      fields: [
-        {
-          name: "market";
-          type: "publicKey";
-          index: false;
-        },
-        {
-          name: "winningOutcome";
-          type: "u8";
-          index: false;
-        },
-        {
-          name: "resolvedAt";
-          type: "i64";
-          index: false;
-        }
-      ];
+      discriminator: [231, 168, 142, 115, 107, 235, 60, 4];
+      name: "disputeConfigUpdated";
     },
     {
-      name: "WinningsRedeemed";
-      fields: [
-        {
-          name: "market";
-          type: "publicKey";
-          index: false;
-        },
-        {
-          name: "winner";
-          type: "publicKey";
-          index: false;
-        },
-        {
-          name: "amountRedeemed";
-          type: "u64";
-          index: false;
-        }
-      ];
+      discriminator: [121, 64, 249, 153, 139, 128, 236, 187];
+      name: "disputeResolved";
     },
     {
-      name: "CreatorPegClaimed";
-      fields: [
-        {
-          name: "market";
-          type: "publicKey";
-          index: false;
-        },
-        {
-          name: "creator";
-          type: "publicKey";
-          index: false;
-        },
-        {
-          name: "amount";
-          type: "u64";
-          index: false;
-        }
-      ];
+      discriminator: [234, 15, 0, 119, 148, 241, 40, 21];
+      name: "feesWithdrawn";
     },
     {
-      name: "FeesWithdrawn";
-      fields: [
-        {
-          name: "market";
-          type: "publicKey";
-          index: false;
-        },
-        {
-          name: "recipient";
-          type: "publicKey";
-          index: false;
-        },
-        {
-          name: "totalAmount";
-          type: "u64";
-          index: false;
-        }
-      ];
+      discriminator: [246, 51, 83, 38, 11, 74, 6, 139];
+      name: "globalStateInitialized";
     },
     {
-      name: "ProgramPaused";
-      fields: [
-        {
-          name: "pausedAt";
-          type: "i64";
-          index: false;
-        }
-      ];
+      discriminator: [184, 135, 229, 146, 221, 189, 51, 112];
+      name: "globalStateMigrated";
     },
     {
-      name: "ProgramUnpaused";
-      fields: [
-        {
-          name: "unpausedAt";
-          type: "i64";
-          index: false;
-        }
-      ];
+      discriminator: [86, 91, 119, 43, 94, 0, 217, 113];
+      name: "marketClosed";
+    },
+    {
+      discriminator: [88, 184, 130, 231, 226, 84, 6, 58];
+      name: "marketCreated";
+    },
+    {
+      discriminator: [196, 0, 143, 107, 73, 246, 52, 158];
+      name: "marketMigrated";
+    },
+    {
+      discriminator: [89, 67, 230, 95, 143, 106, 199, 202];
+      name: "marketResolved";
+    },
+    {
+      discriminator: [116, 168, 80, 22, 59, 218, 186, 73];
+      name: "marketResolvedFromAttestation";
+    },
+    {
+      discriminator: [17, 96, 69, 24, 84, 240, 17, 81];
+      name: "marketResolvedFromPrice";
+    },
+    {
+      discriminator: [53, 72, 160, 209, 15, 222, 46, 157];
+      name: "marketStatusChanged";
+    },
+    {
+      discriminator: [171, 47, 205, 91, 23, 41, 23, 156];
+      name: "marketTagsUpdated";
+    },
+    {
+      discriminator: [217, 12, 138, 39, 108, 75, 89, 26];
+      name: "marketVoided";
+    },
+    {
+      discriminator: [108, 56, 128, 68, 168, 113, 168, 239];
+      name: "orderCancelled";
+    },
+    {
+      discriminator: [96, 130, 204, 234, 169, 219, 216, 227];
+      name: "orderPlaced";
+    },
+    {
+      discriminator: [178, 8, 229, 95, 192, 161, 128, 196];
+      name: "ordersMatched";
+    },
+    {
+      discriminator: [147, 85, 95, 111, 8, 120, 68, 157];
+      name: "outcomeLabelsSet";
+    },
+    {
+      discriminator: [165, 219, 178, 20, 15, 190, 221, 43];
+      name: "outcomeMintInitialized";
+    },
+    {
+      discriminator: [100, 79, 89, 60, 234, 81, 68, 43];
+      name: "outcomeProposed";
+    },
+    {
+      discriminator: [247, 9, 236, 38, 105, 35, 112, 115];
+      name: "programPaused";
+    },
+    {
+      discriminator: [193, 183, 146, 52, 180, 17, 21, 26];
+      name: "programUnpaused";
+    },
+    {
+      discriminator: [135, 138, 207, 78, 188, 208, 210, 132];
+      name: "proposalSettled";
+    },
+    {
+      discriminator: [39, 127, 211, 150, 119, 207, 36, 169];
+      name: "referrerCodeBackfilled";
+    },
+    {
+      discriminator: [136, 64, 242, 99, 4, 244, 208, 130];
+      name: "refundClaimed";
+    },
+    {
+      discriminator: [134, 252, 105, 19, 175, 223, 45, 16];
+      name: "resolutionChallenged";
+    },
+    {
+      discriminator: [36, 66, 248, 103, 208, 214, 102, 214];
+      name: "resolutionVoteMismatch";
+    },
+    {
+      discriminator: [162, 186, 152, 14, 132, 23, 109, 61];
+      name: "resolutionVoteSubmitted";
+    },
+    {
+      discriminator: [10, 249, 212, 63, 65, 48, 252, 243];
+      name: "resolverCouncilCreated";
+    },
+    {
+      discriminator: [240, 98, 69, 10, 253, 234, 226, 65];
+      name: "sharesBought";
+    },
+    {
+      discriminator: [35, 231, 5, 53, 228, 158, 113, 251];
+      name: "sharesSold";
+    },
+    {
+      discriminator: [38, 145, 75, 59, 10, 141, 71, 218];
+      name: "userProfileInitialized";
+    },
+    {
+      discriminator: [165, 63, 125, 179, 230, 236, 63, 99];
+      name: "winningsRedeemed";
     }
   ];
   errors: [
     {
-      code: 0;
-      name: "ProgramPaused";
+      code: 6000;
+      name: "programPaused";
       msg: "Program is currently paused";
     },
     {
-      code: 1;
-      name: "Unauthorized";
+      code: 6001;
+      name: "unauthorized";
       msg: "Unauthorized: only authority can perform this action";
     },
     {
-      code: 2;
-      name: "InvalidOutcomeCount";
-      msg: "Invalid outcome count (must be 2-10)";
+      code: 6002;
+      name: "invalidOutcomeCount";
+      msg: "Invalid outcome count (must be 2-200, or 2-10 for LMSR and CPMM markets)";
     },
     {
-      code: 3;
-      name: "OutcomeCountMismatch";
+      code: 6003;
+      name: "outcomeCountMismatch";
       msg: "Outcome count mismatch between labels and pools";
     },
     {
-      code: 4;
-      name: "InvalidTradingFee";
+      code: 6004;
+      name: "invalidTradingFee";
       msg: "Invalid trading fee (must be 1-500 bps)";
     },
     {
-      code: 5;
-      name: "InvalidOutcomeIndex";
+      code: 6005;
+      name: "invalidOutcomeIndex";
       msg: "Invalid outcome index";
     },
     {
-      code: 6;
-      name: "MarketNotResolved";
+      code: 6006;
+      name: "marketNotResolved";
       msg: "Market not resolved yet";
     },
     {
-      code: 7;
-      name: "MarketAlreadyResolved";
+      code: 6007;
+      name: "marketAlreadyResolved";
       msg: "Market already resolved";
     },
     {
-      code: 8;
-      name: "InvalidResolutionTime";
+      code: 6008;
+      name: "invalidResolutionTime";
       msg: "Invalid resolution time";
     },
     {
-      code: 9;
-      name: "InsufficientFunds";
+      code: 6009;
+      name: "insufficientFunds";
       msg: "Insufficient funds";
     },
     {
-      code: 10;
-      name: "ArithmeticOverflow";
+      code: 6010;
+      name: "arithmeticOverflow";
       msg: "Arithmetic overflow";
     },
     {
-      code: 11;
-      name: "InvalidAmount";
+      code: 6011;
+      name: "invalidAmount";
       msg: "Invalid amount";
     },
     {
-      code: 12;
-      name: "UserProfileNotInitialized";
+      code: 6012;
+      name: "userProfileNotInitialized";
       msg: "User has not initialized their profile";
     },
     {
-      code: 13;
-      name: "InvitorAlreadySet";
+      code: 6013;
+      name: "invitorAlreadySet";
       msg: "Invitor already set for this user";
     },
     {
-      code: 14;
-      name: "CannotInviteYourself";
+      code: 6014;
+      name: "cannotInviteYourself";
       msg: "Cannot invite yourself";
     },
     {
-      code: 15;
-      name: "CreatorPegAlreadyClaimed";
+      code: 6015;
+      name: "creatorPegAlreadyClaimed";
       msg: "Creator peg already claimed";
     },
     {
-      code: 16;
-      name: "NoWinningsToRedeem";
+      code: 6016;
+      name: "noWinningsToRedeem";
       msg: "No winnings to redeem";
     },
     {
-      code: 17;
-      name: "NoFeesToWithdraw";
+      code: 6017;
+      name: "noFeesToWithdraw";
       msg: "No fees to withdraw";
     },
     {
-      code: 18;
-      name: "InvalidTokenMint";
+      code: 6018;
+      name: "invalidTokenMint";
       msg: "Invalid token mint";
     },
     {
-      code: 19;
-      name: "TokenAccountNotOwnedByUser";
+      code: 6019;
+      name: "tokenAccountNotOwnedByUser";
       msg: "Token account must be owned by user";
     },
     {
-      code: 20;
-      name: "InsufficientShares";
+      code: 6020;
+      name: "insufficientShares";
       msg: "Insufficient shares to sell";
     },
     {
-      code: 21;
-      name: "ResolutionTimeNotReached";
+      code: 6021;
+      name: "resolutionTimeNotReached";
       msg: "Market resolution time not reached";
     },
     {
-      code: 22;
-      name: "StringTooLong";
+      code: 6022;
+      name: "stringTooLong";
       msg: "String too long";
     },
     {
-      code: 23;
-      name: "ReferrerCodeInvalid";
+      code: 6023;
+      name: "referrerCodeInvalid";
       msg: "Referrer code invalid";
     },
     {
-      code: 24;
-      name: "UserNotAuthorized";
+      code: 6024;
+      name: "userNotAuthorized";
       msg: "User not authorized";
     },
     {
-      code: 25;
-      name: "CannotWithdrawUnresolved";
+      code: 6025;
+      name: "cannotWithdrawUnresolved";
       msg: "Cannot withdraw from unresolved market";
     },
     {
-      code: 26;
-      name: "MaxOutcomesExceeded";
+      code: 6026;
+      name: "maxOutcomesExceeded";
       msg: "Max outcomes exceeded";
     },
     {
-      code: 27;
-      name: "MinOutcomesNotMet";
+      code: 6027;
+      name: "minOutcomesNotMet";
       msg: "Min outcomes not met";
     },
     {
-      code: 28;
-      name: "ReferrerCodeInUse";
+      code: 6028;
+      name: "referrerCodeInUse";
       msg: "Referrer code already in use";
     },
     {
-      code: 29;
-      name: "InvalidMarketState";
+      code: 6029;
+      name: "invalidMarketState";
       msg: "Invalid market state";
     },
     {
-      code: 30;
-      name: "FeesAlreadyClaimed";
+      code: 6030;
+      name: "feesAlreadyClaimed";
       msg: "Fees already claimed";
     },
     {
-      code: 31;
-      name: "MarketCalculationError";
+      code: 6031;
+      name: "marketCalculationError";
       msg: "Market calculation error";
+    },
+    {
+      code: 6032;
+      name: "invalidLiquidityParam";
+      msg: "Invalid liquidity parameter";
+    },
+    {
+      code: 6033;
+      name: "slippageExceeded";
+      msg: "Slippage tolerance exceeded";
+    },
+    {
+      code: 6034;
+      name: "deadlineExceeded";
+      msg: "Transaction deadline has passed";
+    },
+    {
+      code: 6035;
+      name: "invalidOrderPrice";
+      msg: "Invalid order price";
+    },
+    {
+      code: 6036;
+      name: "orderNotOpen";
+      msg: "Order is not open";
+    },
+    {
+      code: 6037;
+      name: "orderMismatch";
+      msg: "Orders do not match";
+    },
+    {
+      code: 6038;
+      name: "ordersDoNotCross";
+      msg: "Orders do not cross";
+    },
+    {
+      code: 6039;
+      name: "completeSetsUnsupported";
+      msg: "Complete sets are not supported by this market's pricing mode";
+    },
+    {
+      code: 6040;
+      name: "outcomeMintNotInitialized";
+      msg: "Outcome mint has not been initialized";
+    },
+    {
+      code: 6041;
+      name: "invalidOutcomeTokenAccount";
+      msg: "Token account does not hold the expected outcome shares";
+    },
+    {
+      code: 6042;
+      name: "collateralMintNotAllowed";
+      msg: "Collateral mint is not allowed";
+    },
+    {
+      code: 6043;
+      name: "collateralMintAlreadyAllowed";
+      msg: "Collateral mint is already allowed";
+    },
+    {
+      code: 6044;
+      name: "tooManyCollateralMints";
+      msg: "Too many collateral mints";
+    },
+    {
+      code: 6045;
+      name: "invalidVault";
+      msg: "Token account is not the market vault";
+    },
+    {
+      code: 6046;
+      name: "marketVoided";
+      msg: "Market has been voided";
+    },
+    {
+      code: 6047;
+      name: "marketNotVoided";
+      msg: "Market has not been voided";
+    },
+    {
+      code: 6048;
+      name: "invalidPayoutVector";
+      msg: "Payout numerators must cover every outcome and sum to the denominator";
+    },
+    {
+      code: 6049;
+      name: "resolutionNotFinal";
+      msg: "Resolution is not final until the dispute window closes";
+    },
+    {
+      code: 6050;
+      name: "disputeWindowClosed";
+      msg: "Dispute window has closed";
+    },
+    {
+      code: 6051;
+      name: "alreadyDisputed";
+      msg: "Resolution has already been challenged";
+    },
+    {
+      code: 6052;
+      name: "notDisputed";
+      msg: "Resolution has not been challenged";
+    },
+    {
+      code: 6053;
+      name: "invalidDisputePeriod";
+      msg: "Invalid dispute period";
+    },
+    {
+      code: 6054;
+      name: "noPendingProposal";
+      msg: "No proposal is awaiting settlement";
+    },
+    {
+      code: 6055;
+      name: "invalidPriceBands";
+      msg: "Invalid price bands";
+    },
+    {
+      code: 6056;
+      name: "notPriceMarket";
+      msg: "Market does not resolve from a price feed";
+    },
+    {
+      code: 6057;
+      name: "resolvesFromPriceFeed";
+      msg: "Market resolves from its price feed";
+    },
+    {
+      code: 6058;
+      name: "invalidPriceFeed";
+      msg: "Invalid price feed account";
+    },
+    {
+      code: 6059;
+      name: "priceUnavailable";
+      msg: "Price feed is not trading";
+    },
+    {
+      code: 6060;
+      name: "stalePrice";
+      msg: "Price is stale or predates resolution time";
+    },
+    {
+      code: 6061;
+      name: "priceConfidenceTooWide";
+      msg: "Price confidence interval is too wide";
+    },
+    {
+      code: 6062;
+      name: "invalidCouncil";
+      msg: "Council needs 1 to 10 distinct members and a threshold no larger than its size";
+    },
+    {
+      code: 6063;
+      name: "notCouncilMember";
+      msg: "Signer is not a member of the council";
+    },
+    {
+      code: 6064;
+      name: "councilNotResolver";
+      msg: "Council is not this market's resolver";
+    },
+    {
+      code: 6065;
+      name: "resolutionDeadlineNotReached";
+      msg: "Resolution deadline has not passed yet";
+    },
+    {
+      code: 6066;
+      name: "notPublisherMarket";
+      msg: "Market does not resolve from a data publisher";
+    },
+    {
+      code: 6067;
+      name: "resolvesFromPublisher";
+      msg: "Market resolves from its data publisher's attestation";
+    },
+    {
+      code: 6068;
+      name: "invalidAttestation";
+      msg: "Missing or invalid ed25519 attestation from the market's publisher";
+    },
+    {
+      code: 6069;
+      name: "invalidAttestationTimestamp";
+      msg: "Attestation must be timestamped between resolution time and now";
+    },
+    {
+      code: 6070;
+      name: "conflictingResolutionSources";
+      msg: "A market can resolve from a price feed or a data publisher, not both";
+    },
+    {
+      code: 6071;
+      name: "invalidStatusTransition";
+      msg: "Market cannot move to that status from its current one";
+    },
+    {
+      code: 6072;
+      name: "tradingClosed";
+      msg: "Trading is closed for this market";
+    },
+    {
+      code: 6073;
+      name: "outstandingLiabilities";
+      msg: "Market still owes collateral and cannot be closed";
+    },
+    {
+      code: 6074;
+      name: "resolvesFromResolver";
+      msg: "Market has a designated resolver and cannot be resolved by proposal";
+    },
+    {
+      code: 6075;
+      name: "invalidGlobalState";
+      msg: "Invalid global state";
     }
   ];
 };
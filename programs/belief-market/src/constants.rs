@@ -4,24 +4,24 @@
 /// This should be replaced with actual authority during deployment
 pub const PROGRAM_AUTHORITY: &str = "11111111111111111111111111111111";
 
-/// USDC mint address on Eclipse (6 decimal places)
-pub const USDC_MINT: &str = "EPjFWaLb3odcccccccccccccccccccccccccccccccc";
-
-/// USDC decimals
-pub const USDC_DECIMALS: u8 = 6;
+/// Maximum number of collateral mints the global allowlist can hold
+pub const MAX_COLLATERAL_MINTS: usize = 8;
 
 /// Platform wallet to collect fees
 /// This should be replaced during deployment
 pub const PLATFORM_WALLET: &str = "11111111111111111111111111111111";
 
-/// Market creation fee in USDC (5 USDC)
-pub const MARKET_CREATION_FEE_USDC: u64 = 5_000_000; // 5 USDC in lamports
+// Fixed collateral amounts below are in hundredths of a whole collateral token
+// and are scaled to the mint's decimals with `math::collateral_amount`
+
+/// Market creation fee (5 tokens)
+pub const MARKET_CREATION_FEE: u64 = 500;
 
-/// Market creation fee breakdown (must sum to MARKET_CREATION_FEE_USDC)
-pub const MARKET_FEE_PLATFORM_SHARE: u64 = 2_000_000; // $2
-pub const MARKET_FEE_INVITOR_SHARE: u64 = 1_800_000; // $1.80
-pub const MARKET_FEE_REFERRER_SHARE: u64 = 200_000; // $0.20
-pub const MARKET_FEE_CREATOR_PEG: u64 = 1_000_000; // $1 peg
+/// Market creation fee breakdown (must sum to MARKET_CREATION_FEE)
+pub const MARKET_FEE_PLATFORM_SHARE: u64 = 200; // 2 tokens
+pub const MARKET_FEE_INVITOR_SHARE: u64 = 180; // 1.80 tokens
+pub const MARKET_FEE_REFERRER_SHARE: u64 = 20; // 0.20 tokens
+pub const MARKET_FEE_CREATOR_PEG: u64 = 100; // 1 token peg
 
 /// Trading fee distribution
 /// Fees are: 80% creator, 10% invitor, 10% platform
@@ -32,24 +32,73 @@ pub const FEE_PLATFORM_PERCENT: u16 = 10; // 10%
 /// PDA seeds for account derivation
 pub const GLOBAL_STATE_SEED: &[u8] = b"global_state";
 pub const USER_PROFILE_SEED: &[u8] = b"user_profile";
+pub const REFERRER_CODE_SEED: &[u8] = b"referrer_code";
 pub const MARKET_SEED: &[u8] = b"market";
+pub const VAULT_SEED: &[u8] = b"vault";
+pub const OUTCOME_MINT_SEED: &[u8] = b"outcome_mint";
+pub const ORDER_SEED: &[u8] = b"order";
+pub const COST_BASIS_SEED: &[u8] = b"cost_basis";
+pub const COUNCIL_SEED: &[u8] = b"council";
+pub const RESOLUTION_VOTES_SEED: &[u8] = b"resolution_votes";
 
 /// Minimum and maximum outcomes per market
 pub const MIN_OUTCOMES: u8 = 2;
-pub const MAX_OUTCOMES: u8 = 10;
+pub const MAX_OUTCOMES: u8 = 200;
+
+/// Maximum outcomes for LMSR and CPMM markets, whose pricing walks every outcome
+pub const MAX_AMM_OUTCOMES: u8 = 10;
+
+/// Maximum members of a resolver council
+pub const MAX_COUNCIL_MEMBERS: usize = 10;
 
 /// Minimum and maximum trading fee (in basis points)
 pub const MIN_TRADING_FEE_BPS: u16 = 1; // 0.01%
 pub const MAX_TRADING_FEE_BPS: u16 = 500; // 5%
 
+/// Minimum LMSR liquidity parameter `b` (1 token)
+pub const MIN_LMSR_LIQUIDITY_PARAM: u64 = 100;
+
+/// Minimum liquidity a creator must seed a CPMM pool with (10 tokens)
+pub const MIN_CPMM_INITIAL_LIQUIDITY: u64 = 1_000;
+
+/// Limit order prices are collateral per share scaled by this factor
+pub const ORDER_PRICE_SCALE: u64 = 1_000_000;
+
+/// Default time after resolution during which the outcome can be challenged (24 hours)
+pub const DEFAULT_DISPUTE_PERIOD_SECS: i64 = 86_400;
+
+/// Longest dispute period the authority can configure (30 days)
+pub const MAX_DISPUTE_PERIOD_SECS: i64 = 2_592_000;
+
+/// Shortest dispute window on a market resolved from a publisher attestation (1 hour),
+/// even if the configured dispute period is shorter
+pub const MIN_ATTESTATION_DISPUTE_PERIOD_SECS: i64 = 3_600;
+
+/// Default bond a challenger must post (100 tokens)
+pub const DEFAULT_DISPUTE_BOND: u64 = 10_000;
+
+/// Share of a market's accumulated fees paid to a proposer whose outcome stands (10%)
+pub const PROPOSER_REWARD_BPS: u64 = 1000;
+
+/// Oldest Pyth price accepted for resolution, relative to the resolving slot's clock,
+/// and latest publish time accepted after a market's `resolve_at`
+pub const MAX_PRICE_STALENESS_SECS: i64 = 60;
+
+/// Widest Pyth confidence interval accepted for resolution, relative to the price (1%)
+pub const MAX_PRICE_CONF_BPS: u64 = 100;
+
 /// Maximum string lengths
 pub const MAX_OUTCOME_LABEL_LEN: usize = 20;
 pub const MAX_REFERRER_CODE_LEN: usize = 20;
 pub const MAX_TAG_LEN: usize = 15;
 pub const MAX_TAGS_PER_MARKET: usize = 5;
+pub const MAX_EVENT_ID_LEN: usize = 32;
 
 /// Minimum resolution time (1 minute from now)
 pub const MIN_RESOLUTION_TIME_SECS: i64 = 60;
 
 /// Maximum resolution time (10 years from now)
 pub const MAX_RESOLUTION_TIME_SECS: i64 = 315_360_000;
+
+/// Time after `resolve_at` the resolver has before anyone can void the market (7 days)
+pub const RESOLUTION_GRACE_PERIOD_SECS: i64 = 604_800;
@@ -9,7 +9,7 @@ pub enum BelievError {
     #[msg("Unauthorized: only authority can perform this action")]
     Unauthorized = 1,
 
-    #[msg("Invalid outcome count (must be 2-10)")]
+    #[msg("Invalid outcome count (must be 2-200, or 2-10 for LMSR and CPMM markets)")]
     InvalidOutcomeCount = 2,
 
     #[msg("Outcome count mismatch between labels and pools")]
@@ -98,4 +98,136 @@ pub enum BelievError {
 
     #[msg("Market calculation error")]
     MarketCalculationError = 31,
+
+    #[msg("Invalid liquidity parameter")]
+    InvalidLiquidityParam = 32,
+
+    #[msg("Slippage tolerance exceeded")]
+    SlippageExceeded = 33,
+
+    #[msg("Transaction deadline has passed")]
+    DeadlineExceeded = 34,
+
+    #[msg("Invalid order price")]
+    InvalidOrderPrice = 35,
+
+    #[msg("Order is not open")]
+    OrderNotOpen = 36,
+
+    #[msg("Orders do not match")]
+    OrderMismatch = 37,
+
+    #[msg("Orders do not cross")]
+    OrdersDoNotCross = 38,
+
+    #[msg("Complete sets are not supported by this market's pricing mode")]
+    CompleteSetsUnsupported = 39,
+
+    #[msg("Outcome mint has not been initialized")]
+    OutcomeMintNotInitialized = 40,
+
+    #[msg("Token account does not hold the expected outcome shares")]
+    InvalidOutcomeTokenAccount = 41,
+
+    #[msg("Collateral mint is not allowed")]
+    CollateralMintNotAllowed = 42,
+
+    #[msg("Collateral mint is already allowed")]
+    CollateralMintAlreadyAllowed = 43,
+
+    #[msg("Too many collateral mints")]
+    TooManyCollateralMints = 44,
+
+    #[msg("Token account is not the market vault")]
+    InvalidVault = 45,
+
+    #[msg("Market has been voided")]
+    MarketVoided = 46,
+
+    #[msg("Market has not been voided")]
+    MarketNotVoided = 47,
+
+    #[msg("Payout numerators must cover every outcome and sum to the denominator")]
+    InvalidPayoutVector = 48,
+
+    #[msg("Resolution is not final until the dispute window closes")]
+    ResolutionNotFinal = 49,
+
+    #[msg("Dispute window has closed")]
+    DisputeWindowClosed = 50,
+
+    #[msg("Resolution has already been challenged")]
+    AlreadyDisputed = 51,
+
+    #[msg("Resolution has not been challenged")]
+    NotDisputed = 52,
+
+    #[msg("Invalid dispute period")]
+    InvalidDisputePeriod = 53,
+
+    #[msg("No proposal is awaiting settlement")]
+    NoPendingProposal = 54,
+
+    #[msg("Invalid price bands")]
+    InvalidPriceBands = 55,
+
+    #[msg("Market does not resolve from a price feed")]
+    NotPriceMarket = 56,
+
+    #[msg("Market resolves from its price feed")]
+    ResolvesFromPriceFeed = 57,
+
+    #[msg("Invalid price feed account")]
+    InvalidPriceFeed = 58,
+
+    #[msg("Price feed is not trading")]
+    PriceUnavailable = 59,
+
+    #[msg("Price is stale or predates resolution time")]
+    StalePrice = 60,
+
+    #[msg("Price confidence interval is too wide")]
+    PriceConfidenceTooWide = 61,
+
+    #[msg("Council needs 1 to 10 distinct members and a threshold no larger than its size")]
+    InvalidCouncil = 62,
+
+    #[msg("Signer is not a member of the council")]
+    NotCouncilMember = 63,
+
+    #[msg("Council is not this market's resolver")]
+    CouncilNotResolver = 64,
+
+    #[msg("Resolution deadline has not passed yet")]
+    ResolutionDeadlineNotReached = 65,
+
+    #[msg("Market does not resolve from a data publisher")]
+    NotPublisherMarket = 66,
+
+    #[msg("Market resolves from its data publisher's attestation")]
+    ResolvesFromPublisher = 67,
+
+    #[msg("Missing or invalid ed25519 attestation from the market's publisher")]
+    InvalidAttestation = 68,
+
+    #[msg("Attestation must be timestamped between resolution time and now")]
+    InvalidAttestationTimestamp = 69,
+
+    #[msg("A market can resolve from a price feed or a data publisher, not both")]
+    ConflictingResolutionSources = 70,
+
+    #[msg("Market cannot move to that status from its current one")]
+    InvalidStatusTransition = 71,
+
+    #[msg("Trading is closed for this market")]
+    TradingClosed = 72,
+
+    #[msg("Market still owes collateral and cannot be closed")]
+    OutstandingLiabilities = 73,
+
+    #[msg("Market has a designated resolver and cannot be resolved by proposal")]
+    ResolvesFromResolver = 74,
+
+    #[msg("Invalid global state")]
+    InvalidGlobalState = 75,
 }
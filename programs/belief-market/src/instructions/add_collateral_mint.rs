@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use crate::state::GlobalState;
+
+/// Event emitted when a collateral mint is allowed
+#[event]
+pub struct CollateralMintAdded {
+    pub mint: Pubkey,
+}
+
+/// Allow markets to be created with `collateral_mint` - only authority can call
+pub fn add_collateral_mint(ctx: Context<AddCollateralMint>) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.global_state.authority,
+        crate::errors::BelievError::Unauthorized
+    );
+
+    let global_state = &mut ctx.accounts.global_state;
+    let mint = ctx.accounts.collateral_mint.key();
+
+    require!(
+        !global_state.is_collateral_allowed(&mint),
+        crate::errors::BelievError::CollateralMintAlreadyAllowed
+    );
+    require!(
+        global_state.collateral_mints.len() < crate::constants::MAX_COLLATERAL_MINTS,
+        crate::errors::BelievError::TooManyCollateralMints
+    );
+
+    global_state.collateral_mints.push(mint);
+
+    emit!(CollateralMintAdded { mint });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AddCollateralMint<'info> {
+    pub authority: Signer<'info>,
+
+    pub collateral_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [crate::constants::GLOBAL_STATE_SEED],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+}
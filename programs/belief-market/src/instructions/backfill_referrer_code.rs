@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use crate::state::{GlobalState, ReferrerCode, UserProfile};
+
+/// Event emitted when an existing profile's referrer code is registered
+#[event]
+pub struct ReferrerCodeBackfilled {
+    pub user: Pubkey,
+    pub referrer_code: String,
+}
+
+/// Register the referrer code of a profile created before the code registry - only authority
+/// Normalizes the profile's code and creates its `ReferrerCode` entry, failing if
+/// another profile already holds the normalized code. Run it for every existing
+/// profile before `initialize_user` is opened to new users under the registry
+/// check, or a new user could claim an existing profile's code.
+pub fn backfill_referrer_code(ctx: Context<BackfillReferrerCode>) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.global_state.authority,
+        crate::errors::BelievError::Unauthorized
+    );
+
+    let user_profile = &mut ctx.accounts.user_profile;
+    let referrer_code = ReferrerCode::normalize(&user_profile.referrer_code)?;
+
+    // The registry entry already existing means another profile holds the code
+    let referrer_code_account = &mut ctx.accounts.referrer_code_account;
+    require!(
+        referrer_code_account.owner == Pubkey::default(),
+        crate::errors::BelievError::ReferrerCodeInUse
+    );
+
+    user_profile.referrer_code = referrer_code.clone();
+
+    referrer_code_account.code = referrer_code.clone();
+    referrer_code_account.owner = user_profile.owner;
+    referrer_code_account.profile = user_profile.key();
+    referrer_code_account.bump = ctx.bumps.referrer_code_account;
+
+    emit!(ReferrerCodeBackfilled {
+        user: user_profile.owner,
+        referrer_code,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct BackfillReferrerCode<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [crate::constants::USER_PROFILE_SEED, user_profile.owner.as_ref()],
+        bump = user_profile.bump
+    )]
+    pub user_profile: Account<'info, UserProfile>,
+
+    /// Registry entry for the profile's normalized referrer code
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = ReferrerCode::LEN,
+        seeds = [crate::constants::REFERRER_CODE_SEED, ReferrerCode::normalize(&user_profile.referrer_code)?.as_bytes()],
+        bump
+    )]
+    pub referrer_code_account: Account<'info, ReferrerCode>,
+
+    #[account(
+        seeds = [crate::constants::GLOBAL_STATE_SEED],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    pub system_program: Program<'info, System>,
+}
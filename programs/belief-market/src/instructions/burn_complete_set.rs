@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Token, TokenAccount, Transfer};
+use crate::state::{CostBasis, GlobalState, Market, OutcomeBook};
+use super::outcome_tokens;
+
+/// Event emitted when complete sets are burned
+#[event]
+pub struct CompleteSetBurned {
+    pub market: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+}
+
+/// Burn complete sets: return `amount` shares of every outcome for `amount` USDC
+/// Available at any time before resolution
+/// Remaining accounts: `[outcome_mint, user_outcome_account]` for every outcome, in order
+pub fn burn_complete_set<'info>(
+    ctx: Context<'_, '_, '_, 'info, BurnCompleteSet<'info>>,
+    amount: u64,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.global_state.is_paused(),
+        crate::errors::BelievError::ProgramPaused
+    );
+    require!(
+        amount > 0,
+        crate::errors::BelievError::InvalidAmount
+    );
+
+    let market = &mut ctx.accounts.market;
+    let mut book = ctx.accounts.outcome_book.load_mut()?;
+
+    // Complete sets stay redeemable after trading closes, until the market resolves
+    require!(
+        market.status.is_unresolved(),
+        crate::errors::BelievError::MarketAlreadyResolved
+    );
+
+    market.burn_complete_sets(&mut book, amount)?;
+
+    let cost_basis = &mut ctx.accounts.user_cost_basis;
+    cost_basis.init_if_new(market.key(), ctx.accounts.user.key(), ctx.bumps.user_cost_basis);
+    market.remove_cost_basis(cost_basis, amount);
+
+    // Burn one share of every outcome per unit returned
+    let pairs = outcome_tokens::outcome_token_pairs(
+        market,
+        &book,
+        &ctx.accounts.user.key(),
+        ctx.remaining_accounts,
+    )?;
+    for (outcome_mint, user_outcome_account) in pairs {
+        let burn_ix = Burn {
+            mint: outcome_mint.clone(),
+            from: user_outcome_account.clone(),
+            authority: ctx.accounts.user.to_account_info(),
+        };
+        token::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                burn_ix,
+            ),
+            amount,
+        )?;
+    }
+
+    // Transfer from market vault to user
+    let transfer_ix = Transfer {
+        from: ctx.accounts.market_token_account.to_account_info(),
+        to: ctx.accounts.user_token_account.to_account_info(),
+        authority: ctx.accounts.market_vault_authority.to_account_info(),
+    };
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            transfer_ix,
+            &[&[
+                crate::constants::MARKET_SEED,
+                market.market_id.to_le_bytes().as_ref(),
+                &[market.bump],
+            ]],
+        ),
+        amount,
+    )?;
+
+    emit!(CompleteSetBurned {
+        market: market.key(),
+        user: ctx.accounts.user.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct BurnCompleteSet<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = market.collateral_mint,
+        token::authority = user
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    /// Market vault holding the collateral
+    #[account(
+        mut,
+        address = market.vault @ crate::errors::BelievError::InvalidVault,
+        token::authority = market
+    )]
+    pub market_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        address = market.outcome_book
+    )]
+    pub outcome_book: AccountLoader<'info, OutcomeBook>,
+
+    /// Market PDA used as authority for CPI
+    #[account(
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market_vault_authority: AccountInfo<'info>,
+
+    #[account(
+        seeds = [crate::constants::GLOBAL_STATE_SEED],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    /// The user's net cost in this market, refunded if it is voided
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = CostBasis::LEN,
+        seeds = [crate::constants::COST_BASIS_SEED, market.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub user_cost_basis: Account<'info, CostBasis>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
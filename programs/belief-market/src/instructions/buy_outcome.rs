@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::{GlobalState, Market};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
+use crate::state::{CostBasis, GlobalState, Market, OutcomeBook};
 
 /// Event emitted when shares are bought
 #[event]
@@ -13,22 +14,33 @@ pub struct SharesBought {
 }
 
 /// Buy shares of a specific outcome
-/// Uses parimutuel pricing model
+/// Priced by the market's pricing mode (parimutuel, LMSR or CPMM)
+/// Shares are minted to the buyer as outcome tokens
+/// Fails if fewer than `min_shares_out` shares would be issued or `deadline` has passed
 pub fn buy_outcome(
     ctx: Context<BuyOutcome>,
     outcome_index: u8,
     amount_usdc: u64,
+    min_shares_out: u64,
+    deadline: Option<i64>,
 ) -> Result<()> {
     require!(
         !ctx.accounts.global_state.is_paused(),
         crate::errors::BelievError::ProgramPaused
     );
+    if let Some(deadline) = deadline {
+        require!(
+            Clock::get()?.unix_timestamp <= deadline,
+            crate::errors::BelievError::DeadlineExceeded
+        );
+    }
     require!(
         amount_usdc > 0,
         crate::errors::BelievError::InvalidAmount
     );
 
     let market = &mut ctx.accounts.market;
+    let mut book = ctx.accounts.outcome_book.load_mut()?;
 
     // Validate outcome index
     require!(
@@ -36,42 +48,29 @@ pub fn buy_outcome(
         crate::errors::BelievError::InvalidOutcomeIndex
     );
 
-    // Ensure market not resolved
+    // Ensure market is open for trading
     require!(
-        !market.resolved,
-        crate::errors::BelievError::MarketAlreadyResolved
+        market.is_trading(Clock::get()?.unix_timestamp),
+        crate::errors::BelievError::TradingClosed
     );
 
     // Calculate fee
-    let fee_amount = amount_usdc
-        .checked_mul(market.trading_fee_bps as u64)
-        .ok_or(crate::errors::BelievError::ArithmeticOverflow)?
-        .checked_div(10000)
-        .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+    let fee_amount = market.trading_fee(amount_usdc)?;
 
     let amount_after_fee = amount_usdc
         .checked_sub(fee_amount)
         .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
 
-    // Parimutuel model: shares = amount_after_fee / current_price
-    // Current price for an outcome is: outcome_pool / outcome_shares (if shares > 0, else 1)
-    let shares_to_issue = if market.outcome_shares[outcome_index as usize] == 0 {
-        // First buyer: 1 share per lamport
-        amount_after_fee
-    } else {
-        let current_price = market.outcome_pools[outcome_index as usize]
-            .checked_div(market.outcome_shares[outcome_index as usize])
-            .ok_or(crate::errors::BelievError::MarketCalculationError)?;
-        
-        amount_after_fee
-            .checked_div(current_price)
-            .ok_or(crate::errors::BelievError::ArithmeticOverflow)?
-    };
+    let shares_to_issue = market.quote_buy(&book, outcome_index as usize, amount_after_fee)?;
 
     require!(
         shares_to_issue > 0,
         crate::errors::BelievError::InvalidAmount
     );
+    require!(
+        shares_to_issue >= min_shares_out,
+        crate::errors::BelievError::SlippageExceeded
+    );
 
     // Transfer USDC from buyer to market vault
     let transfer_ix = Transfer {
@@ -88,24 +87,41 @@ pub fn buy_outcome(
     )?;
 
     // Update market pools
-    market.outcome_pools[outcome_index as usize] = market.outcome_pools[outcome_index as usize]
+    book.outcome_pools[outcome_index as usize] = book.outcome_pools[outcome_index as usize]
         .checked_add(amount_after_fee)
         .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
 
-    market.outcome_shares[outcome_index as usize] = market.outcome_shares[outcome_index as usize]
+    book.outcome_shares[outcome_index as usize] = book.outcome_shares[outcome_index as usize]
         .checked_add(shares_to_issue)
         .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
 
-    // Distribute fees: 80% creator, 10% invitor, 10% platform
-    let creator_fee = fee_amount
-        .checked_mul(crate::constants::FEE_CREATOR_PERCENT as u64)
-        .ok_or(crate::errors::BelievError::ArithmeticOverflow)?
-        .checked_div(100)
-        .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+    market.settle_amm_buy(&mut book, outcome_index as usize, amount_after_fee, shares_to_issue)?;
 
-    market.accumulated_fees[outcome_index as usize] = market.accumulated_fees[outcome_index as usize]
-        .checked_add(creator_fee)
-        .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+    let cost_basis = &mut ctx.accounts.buyer_cost_basis;
+    cost_basis.init_if_new(market.key(), ctx.accounts.buyer.key(), ctx.bumps.buyer_cost_basis);
+    market.add_cost_basis(cost_basis, amount_after_fee)?;
+
+    // Accumulate fees for creator
+    market.accrue_fee(&mut book, outcome_index as usize, fee_amount)?;
+
+    // Mint outcome tokens to the buyer
+    let mint_ix = MintTo {
+        mint: ctx.accounts.outcome_mint.to_account_info(),
+        to: ctx.accounts.buyer_outcome_account.to_account_info(),
+        authority: market.to_account_info(),
+    };
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            mint_ix,
+            &[&[
+                crate::constants::MARKET_SEED,
+                market.market_id.to_le_bytes().as_ref(),
+                &[market.bump],
+            ]],
+        ),
+        shares_to_issue,
+    )?;
 
     emit!(SharesBought {
         market: market.key(),
@@ -119,36 +135,70 @@ pub fn buy_outcome(
 }
 
 #[derive(Accounts)]
-#[instruction(outcome_index: u8, amount_usdc: u64)]
+#[instruction(outcome_index: u8, amount_usdc: u64, min_shares_out: u64, deadline: Option<i64>)]
 pub struct BuyOutcome<'info> {
     #[account(mut)]
     pub buyer: Signer<'info>,
 
     #[account(
         mut,
-        token::mint = crate::constants::USDC_MINT,
+        token::mint = market.collateral_mint,
         token::authority = buyer
     )]
     pub buyer_token_account: Account<'info, TokenAccount>,
 
+    /// Market vault holding the collateral
     #[account(
         mut,
-        token::mint = crate::constants::USDC_MINT,
+        address = market.vault @ crate::errors::BelievError::InvalidVault,
+        token::authority = market
     )]
     pub market_token_account: Account<'info, TokenAccount>,
 
     #[account(
         mut,
-        seeds = [crate::constants::MARKET_SEED, market.creator.as_ref(), market.resolve_at.to_le_bytes().as_ref()],
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
         bump = market.bump
     )]
     pub market: Account<'info, Market>,
 
+    #[account(
+        mut,
+        address = market.outcome_book
+    )]
+    pub outcome_book: AccountLoader<'info, OutcomeBook>,
+
     #[account(
         seeds = [crate::constants::GLOBAL_STATE_SEED],
         bump = global_state.bump
     )]
     pub global_state: Account<'info, GlobalState>,
 
+    #[account(
+        mut,
+        address = market.outcome_mint(&*outcome_book.load()?, outcome_index as usize)?
+    )]
+    pub outcome_mint: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = outcome_mint,
+        associated_token::authority = buyer
+    )]
+    pub buyer_outcome_account: Account<'info, TokenAccount>,
+
+    /// The buyer's net cost in this market, refunded if it is voided
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = CostBasis::LEN,
+        seeds = [crate::constants::COST_BASIS_SEED, market.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub buyer_cost_basis: Account<'info, CostBasis>,
+
     pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
 }
@@ -0,0 +1,159 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
+use crate::state::{GlobalState, Market, OutcomeBook, Order, OrderSide};
+
+/// Event emitted when a limit order is cancelled
+#[event]
+pub struct OrderCancelled {
+    pub market: Pubkey,
+    pub owner: Pubkey,
+    pub order_id: u64,
+    pub unfilled_quantity: u64,
+    pub escrow_returned: u64,
+}
+
+/// Cancel a limit order and return whatever is left in escrow
+/// Also used to close fully filled orders and reclaim their rent
+pub fn cancel_order(ctx: Context<CancelOrder>) -> Result<()> {
+    require!(
+        !ctx.accounts.global_state.is_paused(),
+        crate::errors::BelievError::ProgramPaused
+    );
+
+    let market = &mut ctx.accounts.market;
+    let order = &ctx.accounts.order;
+
+    if order.escrow > 0 {
+        match order.side {
+            OrderSide::Bid => {
+                // Return escrowed collateral from market vault to owner
+                let transfer_ix = Transfer {
+                    from: ctx.accounts.market_token_account.to_account_info(),
+                    to: ctx.accounts.owner_token_account.to_account_info(),
+                    authority: ctx.accounts.market_vault_authority.to_account_info(),
+                };
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info(),
+                        transfer_ix,
+                        &[&[
+                            crate::constants::MARKET_SEED,
+                            market.market_id.to_le_bytes().as_ref(),
+                            &[market.bump],
+                        ]],
+                    ),
+                    order.escrow,
+                )?;
+
+                market.order_escrow = market.order_escrow
+                    .checked_sub(order.escrow)
+                    .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+            }
+            OrderSide::Ask => {
+                // Re-mint escrowed shares to the owner
+                let mint_ix = MintTo {
+                    mint: ctx.accounts.outcome_mint.to_account_info(),
+                    to: ctx.accounts.owner_outcome_account.to_account_info(),
+                    authority: market.to_account_info(),
+                };
+                token::mint_to(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info(),
+                        mint_ix,
+                        &[&[
+                            crate::constants::MARKET_SEED,
+                            market.market_id.to_le_bytes().as_ref(),
+                            &[market.bump],
+                        ]],
+                    ),
+                    order.escrow,
+                )?;
+
+                market.ask_escrow = market.ask_escrow
+                    .checked_sub(order.escrow)
+                    .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+            }
+        }
+    }
+
+    emit!(OrderCancelled {
+        market: market.key(),
+        owner: order.owner,
+        order_id: order.order_id,
+        unfilled_quantity: order.remaining,
+        escrow_returned: order.escrow,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelOrder<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = market.collateral_mint,
+        token::authority = owner
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    /// Market vault holding the collateral
+    #[account(
+        mut,
+        address = market.vault @ crate::errors::BelievError::InvalidVault,
+        token::authority = market
+    )]
+    pub market_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        address = market.outcome_book
+    )]
+    pub outcome_book: AccountLoader<'info, OutcomeBook>,
+
+    /// Market PDA used as authority for CPI
+    #[account(
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market_vault_authority: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        address = market.outcome_mint(&*outcome_book.load()?, order.outcome_index as usize)?
+    )]
+    pub outcome_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = outcome_mint,
+        associated_token::authority = owner
+    )]
+    pub owner_outcome_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        close = owner,
+        has_one = owner @ crate::errors::BelievError::UserNotAuthorized,
+        constraint = order.market == market.order_seed_address(market.key()),
+        seeds = [crate::constants::ORDER_SEED, market.order_seed_address(market.key()).as_ref(), order.order_id.to_le_bytes().as_ref()],
+        bump = order.bump
+    )]
+    pub order: Account<'info, Order>,
+
+    #[account(
+        seeds = [crate::constants::GLOBAL_STATE_SEED],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{GlobalState, Market, MarketStatus};
+
+/// Event emitted when a resolution is challenged
+#[event]
+pub struct ResolutionChallenged {
+    pub market: Pubkey,
+    pub challenger: Pubkey,
+    pub bond: u64,
+}
+
+/// Challenge a market's resolution during its dispute window
+/// Posts the configured bond into the market vault and escalates the market to
+/// the arbiter; payouts stay blocked until the arbiter rules
+pub fn challenge_resolution(ctx: Context<ChallengeResolution>) -> Result<()> {
+    require!(
+        !ctx.accounts.global_state.is_paused(),
+        crate::errors::BelievError::ProgramPaused
+    );
+
+    let market = &mut ctx.accounts.market;
+
+    // Ensure market is resolved to an outcome
+    require!(
+        market.status != MarketStatus::Disputed,
+        crate::errors::BelievError::AlreadyDisputed
+    );
+    require!(
+        matches!(market.status, MarketStatus::Resolved | MarketStatus::Proposed),
+        crate::errors::BelievError::MarketNotResolved
+    );
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now < market.dispute_ends_at,
+        crate::errors::BelievError::DisputeWindowClosed
+    );
+
+    let bond = ctx.accounts.global_state.dispute_bond_amount(market.collateral_decimals)?;
+
+    // Transfer bond from challenger to market vault
+    if bond > 0 {
+        let transfer_ix = Transfer {
+            from: ctx.accounts.challenger_token_account.to_account_info(),
+            to: ctx.accounts.market_token_account.to_account_info(),
+            authority: ctx.accounts.challenger.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                transfer_ix,
+            ),
+            bond,
+        )?;
+    }
+
+    market.challenger = Some(ctx.accounts.challenger.key());
+    market.challenge_bond = bond;
+    let market_key = market.key();
+    market.set_status(market_key, MarketStatus::Disputed, now)?;
+
+    emit!(ResolutionChallenged {
+        market: market.key(),
+        challenger: ctx.accounts.challenger.key(),
+        bond,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ChallengeResolution<'info> {
+    pub challenger: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = market.collateral_mint,
+        token::authority = challenger
+    )]
+    pub challenger_token_account: Account<'info, TokenAccount>,
+
+    /// Market vault holding the collateral
+    #[account(
+        mut,
+        address = market.vault @ crate::errors::BelievError::InvalidVault,
+        token::authority = market
+    )]
+    pub market_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        seeds = [crate::constants::GLOBAL_STATE_SEED],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    pub token_program: Program<'info, Token>,
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::{GlobalState, Market};
+use crate::state::{GlobalState, Market, OutcomeBook};
 
 /// Event emitted when creator claims their peg
 #[event]
@@ -11,6 +11,8 @@ pub struct CreatorPegClaimed {
 }
 
 /// Claim creator peg ($1 USDC returned after resolution)
+/// For LMSR and CPMM markets this also returns creator liquidity not needed for payouts.
+/// On voided markets the peg may have been forfeited, but liquidity is always returned.
 pub fn claim_peg(ctx: Context<ClaimPeg>) -> Result<()> {
     require!(
         !ctx.accounts.global_state.is_paused(),
@@ -18,6 +20,7 @@ pub fn claim_peg(ctx: Context<ClaimPeg>) -> Result<()> {
     );
 
     let market = &mut ctx.accounts.market;
+    let mut book = ctx.accounts.outcome_book.load_mut()?;
 
     // Verify caller is creator
     require!(
@@ -27,10 +30,18 @@ pub fn claim_peg(ctx: Context<ClaimPeg>) -> Result<()> {
 
     // Ensure market is resolved
     require!(
-        market.resolved,
+        !market.status.is_unresolved(),
         crate::errors::BelievError::MarketNotResolved
     );
 
+    // Ensure the resolution can no longer be challenged
+    let market_key = market.key();
+    market.finalize_if_due(market_key, Clock::get()?.unix_timestamp)?;
+    require!(
+        market.status.is_settled(),
+        crate::errors::BelievError::ResolutionNotFinal
+    );
+
     // Ensure peg not already claimed
     require!(
         !market.creator_peg_claimed,
@@ -38,8 +49,12 @@ pub fn claim_peg(ctx: Context<ClaimPeg>) -> Result<()> {
     );
 
     let peg_amount = market.creator_peg_amount;
+    let liquidity_refund = market.liquidity_refund(&book)?;
+    let claim_amount = peg_amount
+        .checked_add(liquidity_refund)
+        .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
     require!(
-        peg_amount > 0,
+        claim_amount > 0,
         crate::errors::BelievError::InvalidAmount
     );
 
@@ -55,20 +70,30 @@ pub fn claim_peg(ctx: Context<ClaimPeg>) -> Result<()> {
             transfer_ix,
             &[&[
                 crate::constants::MARKET_SEED,
-                market.creator.as_ref(),
-                market.resolve_at.to_le_bytes().as_ref(),
+                market.market_id.to_le_bytes().as_ref(),
                 &[market.bump],
             ]],
         ),
-        peg_amount,
+        claim_amount,
     )?;
 
+    if liquidity_refund > 0 {
+        if market.is_voided() {
+            // The pools are reserved for refunds; only collateral beyond the
+            // outstanding cost bases comes out of them
+            let from_pools = liquidity_refund.saturating_sub(market.liquidity_amount);
+            market.withdraw_from_pools(&mut book, 0, from_pools)?;
+            market.liquidity_amount -= liquidity_refund - from_pools;
+        } else {
+            market.withdraw_from_pools(&mut book, 0, liquidity_refund)?;
+        }
+    }
     market.creator_peg_claimed = true;
 
     emit!(CreatorPegClaimed {
         market: market.key(),
         creator: market.creator,
-        amount: peg_amount,
+        amount: claim_amount,
     });
 
     Ok(())
@@ -80,27 +105,35 @@ pub struct ClaimPeg<'info> {
 
     #[account(
         mut,
-        token::mint = crate::constants::USDC_MINT,
+        token::mint = market.collateral_mint,
         token::authority = creator
     )]
     pub creator_token_account: Account<'info, TokenAccount>,
 
+    /// Market vault holding the collateral
     #[account(
         mut,
-        token::mint = crate::constants::USDC_MINT,
+        address = market.vault @ crate::errors::BelievError::InvalidVault,
+        token::authority = market
     )]
     pub market_token_account: Account<'info, TokenAccount>,
 
     #[account(
         mut,
-        seeds = [crate::constants::MARKET_SEED, market.creator.as_ref(), market.resolve_at.to_le_bytes().as_ref()],
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
         bump = market.bump
     )]
     pub market: Account<'info, Market>,
 
+    #[account(
+        mut,
+        address = market.outcome_book
+    )]
+    pub outcome_book: AccountLoader<'info, OutcomeBook>,
+
     /// Market PDA used as authority for CPI
     #[account(
-        seeds = [crate::constants::MARKET_SEED, market.creator.as_ref(), market.resolve_at.to_le_bytes().as_ref()],
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
         bump = market.bump
     )]
     pub market_vault_authority: AccountInfo<'info>,
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{CostBasis, GlobalState, Market, OutcomeBook};
+
+/// Event emitted when a holder is refunded from a voided market
+#[event]
+pub struct RefundClaimed {
+    pub market: Pubkey,
+    pub holder: Pubkey,
+    pub net_cost: u64,
+    pub amount_refunded: u64,
+}
+
+/// Claim a refund of the caller's net cost basis in a voided market
+/// Pays back what the caller put into the market's pools less what they took
+/// out, trading fees excluded, scaled down only if the pools cannot cover every
+/// holder's basis (see `Market::quote_refund`). The caller's `CostBasis` account
+/// is closed; outcome tokens are left in place and carry no further claim.
+pub fn claim_refund(ctx: Context<ClaimRefund>) -> Result<()> {
+    require!(
+        !ctx.accounts.global_state.is_paused(),
+        crate::errors::BelievError::ProgramPaused
+    );
+
+    let market = &mut ctx.accounts.market;
+    let mut book = ctx.accounts.outcome_book.load_mut()?;
+    let cost_basis = &mut ctx.accounts.cost_basis;
+
+    require!(
+        market.is_voided(),
+        crate::errors::BelievError::MarketNotVoided
+    );
+
+    let net_cost = cost_basis.net_cost;
+    require!(
+        net_cost > 0,
+        crate::errors::BelievError::InvalidAmount
+    );
+
+    let amount_refunded = market.quote_refund(&book, net_cost)?;
+
+    if amount_refunded > 0 {
+        let transfer_ix = Transfer {
+            from: ctx.accounts.market_token_account.to_account_info(),
+            to: ctx.accounts.holder_token_account.to_account_info(),
+            authority: ctx.accounts.market_vault_authority.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                transfer_ix,
+                &[&[
+                    crate::constants::MARKET_SEED,
+                    market.market_id.to_le_bytes().as_ref(),
+                    &[market.bump],
+                ]],
+            ),
+            amount_refunded,
+        )?;
+        market.withdraw_from_pools(&mut book, 0, amount_refunded)?;
+    }
+    market.remove_cost_basis(cost_basis, net_cost);
+
+    emit!(RefundClaimed {
+        market: market.key(),
+        holder: ctx.accounts.holder.key(),
+        net_cost,
+        amount_refunded,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimRefund<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = market.collateral_mint,
+        token::authority = holder
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+
+    /// Holder's cost basis in the market, closed to the holder
+    #[account(
+        mut,
+        close = holder,
+        seeds = [crate::constants::COST_BASIS_SEED, market.key().as_ref(), holder.key().as_ref()],
+        bump = cost_basis.bump
+    )]
+    pub cost_basis: Account<'info, CostBasis>,
+
+    /// Market vault holding the collateral
+    #[account(
+        mut,
+        address = market.vault @ crate::errors::BelievError::InvalidVault,
+        token::authority = market
+    )]
+    pub market_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        address = market.outcome_book
+    )]
+    pub outcome_book: AccountLoader<'info, OutcomeBook>,
+
+    /// Market PDA used as authority for CPI
+    #[account(
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market_vault_authority: AccountInfo<'info>,
+
+    #[account(
+        seeds = [crate::constants::GLOBAL_STATE_SEED],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    pub token_program: Program<'info, Token>,
+}
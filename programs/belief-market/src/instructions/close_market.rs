@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, spl_token::instruction::AuthorityType, CloseAccount, Mint, SetAuthority, Token, TokenAccount, Transfer};
+use crate::state::{GlobalState, Market, OutcomeBook};
+
+/// Event emitted when a settled market and its vault are closed
+#[event]
+pub struct MarketClosed {
+    pub market: Pubkey,
+    pub creator: Pubkey,
+    pub dust_swept: u64,
+}
+
+/// Close a fully settled market, its outcome book and its vault - permissionless
+/// Only once the market is finalized or voided, its fees are withdrawn and no
+/// outcome token still in circulation is owed a payout or refund. What is left
+/// in the vault is rounding remainder and goes to the platform wallet, and the
+/// rent of all three accounts is returned to the creator.
+/// SPL Token mints cannot be closed, so each outcome mint is retired instead by
+/// revoking the market's mint authority.
+/// Remaining accounts: every initialized outcome mint, in outcome order
+pub fn close_market<'info>(ctx: Context<'_, '_, '_, 'info, CloseMarket<'info>>) -> Result<()> {
+    let market = &ctx.accounts.market;
+    let book = ctx.accounts.outcome_book.load()?;
+
+    require!(
+        market.status.is_settled(),
+        crate::errors::BelievError::ResolutionNotFinal
+    );
+
+    // Outcomes whose mint was never created have no holders
+    let mut supplies = vec![0u64; book.outcome_count()];
+    let mut mint_infos = ctx.remaining_accounts.iter();
+    for (i, mint) in book.mints().iter().enumerate() {
+        if *mint == Pubkey::default() {
+            continue;
+        }
+        let mint_info = mint_infos
+            .next()
+            .filter(|info| info.key() == *mint && info.owner == &token::ID)
+            .ok_or(crate::errors::BelievError::InvalidTokenMint)?;
+        supplies[i] = Mint::try_deserialize(&mut &mint_info.data.borrow()[..])?.supply;
+    }
+    require!(
+        mint_infos.next().is_none(),
+        crate::errors::BelievError::InvalidTokenMint
+    );
+    require!(
+        !market.has_outstanding_liabilities(&book, &supplies)?,
+        crate::errors::BelievError::OutstandingLiabilities
+    );
+
+    let signer_seeds: &[&[u8]] = &[
+        crate::constants::MARKET_SEED,
+        &market.market_id.to_le_bytes(),
+        &[market.bump],
+    ];
+
+    // Retire the outcome mints
+    for mint_info in ctx.remaining_accounts {
+        token::set_authority(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                SetAuthority {
+                    current_authority: market.to_account_info(),
+                    account_or_mint: mint_info.clone(),
+                },
+                &[signer_seeds],
+            ),
+            AuthorityType::MintTokens,
+            None,
+        )?;
+    }
+
+    // Sweep the rounding remainder to the platform
+    let dust_swept = ctx.accounts.market_token_account.amount;
+    if dust_swept > 0 {
+        let transfer_ix = Transfer {
+            from: ctx.accounts.market_token_account.to_account_info(),
+            to: ctx.accounts.platform_token_account.to_account_info(),
+            authority: market.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                transfer_ix,
+                &[signer_seeds],
+            ),
+            dust_swept,
+        )?;
+    }
+
+    let close_ix = CloseAccount {
+        account: ctx.accounts.market_token_account.to_account_info(),
+        destination: ctx.accounts.creator.to_account_info(),
+        authority: market.to_account_info(),
+    };
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        close_ix,
+        &[signer_seeds],
+    ))?;
+
+    emit!(MarketClosed {
+        market: market.key(),
+        creator: market.creator,
+        dust_swept,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseMarket<'info> {
+    pub caller: Signer<'info>,
+
+    /// CHECK: Market creator receiving the rent; checked against the market
+    #[account(
+        mut,
+        address = market.creator @ crate::errors::BelievError::UserNotAuthorized
+    )]
+    pub creator: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = creator,
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        close = creator,
+        address = market.outcome_book
+    )]
+    pub outcome_book: AccountLoader<'info, OutcomeBook>,
+
+    /// Market vault holding the collateral
+    #[account(
+        mut,
+        address = market.vault @ crate::errors::BelievError::InvalidVault,
+        token::authority = market
+    )]
+    pub market_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = market.collateral_mint,
+        token::authority = global_state.platform_wallet
+    )]
+    pub platform_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [crate::constants::GLOBAL_STATE_SEED],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::state::{Market, MarketStatus};
+
+/// Halt trading on a market that has reached its resolution time - permissionless
+/// Trading already stops at `resolve_at`; this records the `Closed` status for
+/// markets still waiting on their resolver.
+pub fn close_trading(ctx: Context<CloseTrading>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Check resolution time has passed
+    require!(
+        now >= market.resolve_at,
+        crate::errors::BelievError::ResolutionTimeNotReached
+    );
+
+    let market_key = market.key();
+    market.set_status(market_key, MarketStatus::Closed, now)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseTrading<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+}
@@ -1,41 +1,89 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::{GlobalState, UserProfile, Market};
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::state::{DataPublisher, GlobalState, UserProfile, Market, MarketStatus, OutcomeBook, PriceBands, PricingMode};
 
 /// Event emitted when a market is created
 #[event]
 pub struct MarketCreated {
     pub market: Pubkey,
+    pub market_id: u64,
     pub creator: Pubkey,
+    pub collateral_mint: Pubkey,
+    pub resolver: Pubkey,
     pub num_outcomes: u8,
     pub trading_fee_bps: u16,
     pub resolve_at: i64,
+    pub pricing_mode: PricingMode,
+    pub liquidity_param: u64,
+}
+
+/// Parameters of a new market
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreateMarketParams {
+    pub num_outcomes: u8,
+    /// Labels for the first outcomes; the rest are set with `set_outcome_labels`
+    pub outcome_labels: Vec<String>,
+    pub tags: Vec<String>,
+    pub trading_fee_bps: u16,
+    pub resolve_at: i64,
+    pub pricing_mode: PricingMode,
+    /// LMSR `b` or CPMM seed liquidity in collateral base units; 0 for parimutuel
+    pub liquidity_param: u64,
+    pub price_bands: Option<PriceBands>,
+    pub resolver: Option<Pubkey>,
+    pub publisher: Option<DataPublisher>,
 }
 
 /// Create a new prediction market
-/// Requires 5 USDC: $2 platform, $1.80 invitor, $0.20 referrer, $1 creator peg
-pub fn create_market(
-    ctx: Context<CreateMarket>,
-    num_outcomes: u8,
-    outcome_labels: Vec<String>,
-    tags: Vec<String>,
-    trading_fee_bps: u16,
-    resolve_at: i64,
-) -> Result<()> {
+/// Each market takes the next ID from the global counter and is seeded by it
+/// Requires 5 collateral tokens: 2 platform, 1.80 invitor, 0.20 referrer, 1 creator peg
+/// Denominated in `collateral_mint`, which must be on the global allowlist; fixed
+/// amounts are scaled to the mint's decimals
+/// LMSR markets additionally require the creator to fund `b * ln(num_outcomes)`,
+/// and CPMM markets require the creator to seed the pool with `liquidity_param`
+/// Markets created with `price_bands` resolve permissionlessly from their Pyth feed
+/// and markets created with `publisher` from the publisher's signed attestation;
+/// others are resolved by `resolver`, which defaults to the global authority
+/// Markets still unresolved `RESOLUTION_GRACE_PERIOD_SECS` after `resolve_at` can be voided by anyone
+/// Per-outcome state lives in `outcome_book`, which the client allocates beforehand.
+/// Labels for outcomes past `outcome_labels.len()` are set with `set_outcome_labels`.
+pub fn create_market(ctx: Context<CreateMarket>, params: CreateMarketParams) -> Result<()> {
+    let CreateMarketParams {
+        num_outcomes,
+        outcome_labels,
+        tags,
+        trading_fee_bps,
+        resolve_at,
+        pricing_mode,
+        liquidity_param,
+        price_bands,
+        resolver,
+        publisher,
+    } = params;
     let now = Clock::get()?.unix_timestamp;
+    let decimals = ctx.accounts.collateral_mint.decimals;
+    let collateral_amount = |hundredths: u64| {
+        crate::math::collateral_amount(hundredths, decimals)
+            .ok_or(crate::errors::BelievError::ArithmeticOverflow)
+    };
 
     // Validate inputs
     require!(
-        num_outcomes >= Market::MIN_OUTCOMES && num_outcomes <= Market::MAX_OUTCOMES,
+        (Market::MIN_OUTCOMES..=Market::MAX_OUTCOMES).contains(&num_outcomes),
+        crate::errors::BelievError::InvalidOutcomeCount
+    );
+    require!(
+        pricing_mode == PricingMode::Parimutuel
+            || num_outcomes <= crate::constants::MAX_AMM_OUTCOMES,
         crate::errors::BelievError::InvalidOutcomeCount
     );
     require!(
-        outcome_labels.len() == num_outcomes as usize,
+        outcome_labels.len() <= num_outcomes as usize,
         crate::errors::BelievError::OutcomeCountMismatch
     );
     require!(
-        trading_fee_bps >= crate::constants::MIN_TRADING_FEE_BPS 
-            && trading_fee_bps <= crate::constants::MAX_TRADING_FEE_BPS,
+        (crate::constants::MIN_TRADING_FEE_BPS..=crate::constants::MAX_TRADING_FEE_BPS)
+            .contains(&trading_fee_bps),
         crate::errors::BelievError::InvalidTradingFee
     );
     require!(
@@ -62,13 +110,61 @@ pub fn create_market(
         );
     }
 
+    // Price-band markets need one ascending threshold between each pair of outcomes
+    if let Some(bands) = &price_bands {
+        require!(
+            bands.thresholds.len() == num_outcomes as usize - 1
+                && bands.thresholds.windows(2).all(|pair| pair[0] < pair[1]),
+            crate::errors::BelievError::InvalidPriceBands
+        );
+    }
+
+    if let Some(publisher) = &publisher {
+        require!(
+            price_bands.is_none(),
+            crate::errors::BelievError::ConflictingResolutionSources
+        );
+        require!(
+            publisher.event_id.len() <= crate::constants::MAX_EVENT_ID_LEN,
+            crate::errors::BelievError::StringTooLong
+        );
+    }
+
+    // LMSR and CPMM markets need liquidity; parimutuel markets must not set any
+    let liquidity_amount = match pricing_mode {
+        PricingMode::Parimutuel => {
+            require!(
+                liquidity_param == 0,
+                crate::errors::BelievError::InvalidLiquidityParam
+            );
+            0
+        }
+        PricingMode::Lmsr => {
+            require!(
+                liquidity_param >= collateral_amount(crate::constants::MIN_LMSR_LIQUIDITY_PARAM)?,
+                crate::errors::BelievError::InvalidLiquidityParam
+            );
+            crate::math::lmsr::subsidy(liquidity_param, num_outcomes)
+                .ok_or(crate::errors::BelievError::MarketCalculationError)?
+        }
+        PricingMode::Cpmm => {
+            require!(
+                liquidity_param >= collateral_amount(crate::constants::MIN_CPMM_INITIAL_LIQUIDITY)?,
+                crate::errors::BelievError::InvalidLiquidityParam
+            );
+            liquidity_param
+        }
+    };
+
     // Check program not paused
     require!(
         !ctx.accounts.global_state.is_paused(),
         crate::errors::BelievError::ProgramPaused
     );
 
-    // Transfer 5 USDC creation fee from creator
+    // Transfer the creation fee from creator, less the peg held by the market
+    let creation_fee = collateral_amount(crate::constants::MARKET_CREATION_FEE)?;
+    let creator_peg = collateral_amount(crate::constants::MARKET_FEE_CREATOR_PEG)?;
     let transfer_ix = Transfer {
         from: ctx.accounts.creator_token_account.to_account_info(),
         to: ctx.accounts.fee_destination.to_account_info(),
@@ -79,67 +175,125 @@ pub fn create_market(
             ctx.accounts.token_program.to_account_info(),
             transfer_ix,
         ),
-        crate::constants::MARKET_CREATION_FEE_USDC,
+        creation_fee - creator_peg,
+    )?;
+
+    // Escrow the creator peg plus the LMSR worst-case loss or the complete sets
+    // seeding the CPMM pool
+    let vault_deposit = creator_peg
+        .checked_add(liquidity_amount)
+        .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+    let transfer_ix = Transfer {
+        from: ctx.accounts.creator_token_account.to_account_info(),
+        to: ctx.accounts.market_token_account.to_account_info(),
+        authority: ctx.accounts.creator.to_account_info(),
+    };
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            transfer_ix,
+        ),
+        vault_deposit,
     )?;
 
     // Initialize market
+    let market_id = ctx.accounts.global_state.next_market_id()?;
     let market = &mut ctx.accounts.market;
     market.creator = ctx.accounts.creator.key();
     market.invitor = ctx.accounts.creator_profile.invitor;
     market.referrer = None; // Referrers only earn from creation via fee split
+    market.collateral_mint = ctx.accounts.collateral_mint.key();
+    market.collateral_decimals = ctx.accounts.collateral_mint.decimals;
+    market.vault = ctx.accounts.market_token_account.key();
+    market.resolver = resolver.unwrap_or(ctx.accounts.global_state.authority);
+    market.outcome_book = ctx.accounts.outcome_book.key();
     market.num_outcomes = num_outcomes;
-    market.outcome_labels = outcome_labels;
-    market.outcome_pools = vec![0; num_outcomes as usize];
-    market.outcome_shares = vec![0; num_outcomes as usize];
     market.tags = tags;
+    market.price_bands = price_bands;
+    market.publisher = publisher;
     market.trading_fee_bps = trading_fee_bps;
+    market.pricing_mode = pricing_mode;
+    market.liquidity_param = liquidity_param;
+    market.liquidity_amount = liquidity_amount;
     market.resolve_at = resolve_at;
-    market.resolved = false;
-    market.winning_outcome = None;
-    market.creator_peg_amount = crate::constants::MARKET_FEE_CREATOR_PEG;
+    market.resolution_deadline = resolve_at
+        .checked_add(crate::constants::RESOLUTION_GRACE_PERIOD_SECS)
+        .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+    market.status = MarketStatus::Draft;
+    market.payout_denominator = 0;
+    market.dispute_ends_at = 0;
+    market.attested_at = 0;
+    market.challenger = None;
+    market.challenge_bond = 0;
+    market.proposer = None;
+    market.proposal_bond = 0;
+    market.creator_peg_amount = creator_peg;
     market.creator_peg_claimed = false;
-    market.accumulated_fees = vec![0; num_outcomes as usize];
+    market.void_reason = None;
+    market.next_order_id = 0;
+    market.order_escrow = 0;
+    market.ask_escrow = 0;
+    market.total_cost_basis = 0;
     market.created_at = now;
     market.bump = ctx.bumps.market;
+    market.market_id = market_id;
+    market.legacy_address = None;
+
+    // Initialize the outcome book; the pools, shares and fees start zeroed
+    let mut book = ctx.accounts.outcome_book.load_init()?;
+    book.market = market.key();
+    book.num_outcomes = num_outcomes as u16;
+    for (i, label) in outcome_labels.iter().enumerate() {
+        book.set_label(i, label)?;
+    }
+    if pricing_mode == PricingMode::Cpmm {
+        book.amm_balances[..num_outcomes as usize].fill(liquidity_param);
+    }
 
     emit!(MarketCreated {
         market: market.key(),
+        market_id,
         creator: market.creator,
+        collateral_mint: market.collateral_mint,
+        resolver: market.resolver,
         num_outcomes,
         trading_fee_bps,
         resolve_at,
+        pricing_mode,
+        liquidity_param,
     });
 
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(
-    num_outcomes: u8,
-    outcome_labels: Vec<String>,
-    tags: Vec<String>,
-    trading_fee_bps: u16,
-    resolve_at: i64,
-)]
+#[instruction(params: CreateMarketParams)]
 pub struct CreateMarket<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
 
     #[account(
         mut,
-        token::mint = crate::constants::USDC_MINT,
+        token::mint = collateral_mint,
         token::authority = creator
     )]
     pub creator_token_account: Account<'info, TokenAccount>,
 
+    #[account(
+        constraint = global_state.is_collateral_allowed(&collateral_mint.key())
+            @ crate::errors::BelievError::CollateralMintNotAllowed
+    )]
+    pub collateral_mint: Account<'info, Mint>,
+
     #[account(
         mut,
-        token::mint = crate::constants::USDC_MINT,
+        token::mint = collateral_mint,
         token::authority = global_state.platform_wallet
     )]
     pub fee_destination: Account<'info, TokenAccount>,
 
     #[account(
+        mut,
         seeds = [crate::constants::GLOBAL_STATE_SEED],
         bump = global_state.bump
     )]
@@ -154,14 +308,27 @@ pub struct CreateMarket<'info> {
     #[account(
         init,
         payer = creator,
-        space = 8 + 32 + 1 + 32 + (1 + 32) + (1 + 32) + 1 + 
-                4 + 100 + 4 + 80 + 4 + 80 + 4 + 75 + 
-                2 + 8 + 1 + (1 + 1) + 8 + 1 + 4 + 80 + 8,
-        seeds = [crate::constants::MARKET_SEED, creator.key().as_ref(), resolve_at.to_le_bytes().as_ref()],
+        space = Market::space(&params.tags, &params.price_bands, &params.publisher),
+        seeds = [crate::constants::MARKET_SEED, (global_state.market_count + 1).to_le_bytes().as_ref()],
         bump
     )]
     pub market: Account<'info, Market>,
 
+    /// Per-outcome state of the market, allocated by the client (`OutcomeBook::LEN` bytes)
+    #[account(zero)]
+    pub outcome_book: AccountLoader<'info, OutcomeBook>,
+
+    /// Market vault holding the collateral, owned by the market PDA
+    #[account(
+        init,
+        payer = creator,
+        token::mint = collateral_mint,
+        token::authority = market,
+        seeds = [crate::constants::VAULT_SEED, market.key().as_ref()],
+        bump
+    )]
+    pub market_token_account: Account<'info, TokenAccount>,
+
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
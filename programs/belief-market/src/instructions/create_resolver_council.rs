@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::state::ResolverCouncil;
+
+/// Event emitted when a resolver council is created
+#[event]
+pub struct ResolverCouncilCreated {
+    pub council: Pubkey,
+    pub authority: Pubkey,
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+/// Create an M-of-N resolver council
+/// Markets name the council's address as their `resolver` to be settled by
+/// `threshold` matching member votes only; `propose_outcome` and
+/// `settle_proposal` reject them like any market with a designated resolver
+pub fn create_resolver_council(
+    ctx: Context<CreateResolverCouncil>,
+    council_id: u64,
+    members: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    require!(
+        !members.is_empty() && members.len() <= crate::constants::MAX_COUNCIL_MEMBERS,
+        crate::errors::BelievError::InvalidCouncil
+    );
+    require!(
+        threshold > 0 && threshold as usize <= members.len(),
+        crate::errors::BelievError::InvalidCouncil
+    );
+    for (i, member) in members.iter().enumerate() {
+        require!(
+            !members[..i].contains(member),
+            crate::errors::BelievError::InvalidCouncil
+        );
+    }
+
+    let council = &mut ctx.accounts.council;
+    council.authority = ctx.accounts.authority.key();
+    council.council_id = council_id;
+    council.members = members;
+    council.threshold = threshold;
+    council.bump = ctx.bumps.council;
+
+    emit!(ResolverCouncilCreated {
+        council: council.key(),
+        authority: council.authority,
+        members: council.members.clone(),
+        threshold,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(council_id: u64)]
+pub struct CreateResolverCouncil<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = ResolverCouncil::LEN,
+        seeds = [crate::constants::COUNCIL_SEED, authority.key().as_ref(), council_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub council: Account<'info, ResolverCouncil>,
+
+    pub system_program: Program<'info, System>,
+}
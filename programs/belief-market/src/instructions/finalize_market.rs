@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::state::{Market, MarketStatus};
+
+/// Finalize a resolved market whose dispute window closed unchallenged - permissionless
+/// Payout instructions finalize such markets on their own; this lets anyone
+/// record the `Finalized` status without claiming.
+pub fn finalize_market(ctx: Context<FinalizeMarket>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let now = Clock::get()?.unix_timestamp;
+
+    let market_key = market.key();
+    market.finalize_if_due(market_key, now)?;
+    require!(
+        market.status == MarketStatus::Finalized,
+        crate::errors::BelievError::ResolutionNotFinal
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FinalizeMarket<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+}
@@ -19,6 +19,11 @@ pub fn initialize_global(
     global_state.authority = ctx.accounts.authority.key();
     global_state.platform_wallet = platform_wallet;
     global_state.paused = false;
+    global_state.collateral_mints = Vec::new();
+    global_state.dispute_period_secs = crate::constants::DEFAULT_DISPUTE_PERIOD_SECS;
+    global_state.dispute_bond = crate::constants::DEFAULT_DISPUTE_BOND;
+    global_state.arbiter = global_state.authority;
+    global_state.market_count = 0;
     global_state.bump = ctx.bumps.global_state;
 
     emit!(GlobalStateInitialized {
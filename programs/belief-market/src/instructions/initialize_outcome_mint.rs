@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+use crate::state::{Market, MarketStatus, OutcomeBook};
+
+/// Event emitted when an outcome share mint is created
+#[event]
+pub struct OutcomeMintInitialized {
+    pub market: Pubkey,
+    pub outcome_index: u8,
+    pub mint: Pubkey,
+}
+
+/// Create the SPL mint for one outcome's shares
+/// Permissionless; the market PDA is the mint authority. The market opens for
+/// trading once every outcome's mint exists.
+pub fn initialize_outcome_mint(
+    ctx: Context<InitializeOutcomeMint>,
+    outcome_index: u8,
+) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let mut book = ctx.accounts.outcome_book.load_mut()?;
+
+    // Validate outcome index
+    require!(
+        (outcome_index as usize) < market.num_outcomes as usize,
+        crate::errors::BelievError::InvalidOutcomeIndex
+    );
+
+    book.outcome_mints[outcome_index as usize] = ctx.accounts.outcome_mint.key();
+
+    // Trading opens once every outcome has a mint
+    if market.status == MarketStatus::Draft
+        && book.mints().iter().all(|mint| *mint != Pubkey::default())
+    {
+        let market_key = market.key();
+        market.set_status(market_key, MarketStatus::Open, Clock::get()?.unix_timestamp)?;
+    }
+
+    emit!(OutcomeMintInitialized {
+        market: market.key(),
+        outcome_index,
+        mint: ctx.accounts.outcome_mint.key(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(outcome_index: u8)]
+pub struct InitializeOutcomeMint<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        address = market.outcome_book
+    )]
+    pub outcome_book: AccountLoader<'info, OutcomeBook>,
+
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = market.collateral_decimals,
+        mint::authority = market,
+        seeds = [crate::constants::OUTCOME_MINT_SEED, market.key().as_ref(), &[outcome_index]],
+        bump
+    )]
+    pub outcome_mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
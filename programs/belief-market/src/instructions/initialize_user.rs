@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::UserProfile;
+use crate::state::{ReferrerCode, UserProfile};
 
 /// Event emitted when a user profile is initialized
 #[event]
@@ -10,17 +10,19 @@ pub struct UserProfileInitialized {
 
 /// Initialize a user profile
 /// Called once per user to set up their referrer code
+/// The code is normalized to lowercase and registered in a `ReferrerCode` PDA
+/// seeded by it, so each code belongs to at most one user.
 pub fn initialize_user(
     ctx: Context<InitializeUser>,
     referrer_code: String,
 ) -> Result<()> {
+    let referrer_code = ReferrerCode::normalize(&referrer_code)?;
+
+    // The registry entry already existing means another user holds the code
+    let referrer_code_account = &mut ctx.accounts.referrer_code_account;
     require!(
-        referrer_code.len() <= UserProfile::MAX_REFERRER_CODE_LEN,
-        crate::errors::BelievError::StringTooLong
-    );
-    require!(
-        !referrer_code.is_empty(),
-        crate::errors::BelievError::ReferrerCodeInvalid
+        referrer_code_account.owner == Pubkey::default(),
+        crate::errors::BelievError::ReferrerCodeInUse
     );
 
     let user_profile = &mut ctx.accounts.user_profile;
@@ -30,6 +32,11 @@ pub fn initialize_user(
     user_profile.referrer_code = referrer_code.clone();
     user_profile.bump = ctx.bumps.user_profile;
 
+    referrer_code_account.code = referrer_code.clone();
+    referrer_code_account.owner = user_profile.owner;
+    referrer_code_account.profile = user_profile.key();
+    referrer_code_account.bump = ctx.bumps.referrer_code_account;
+
     emit!(UserProfileInitialized {
         user: user_profile.owner,
         referrer_code,
@@ -53,5 +60,15 @@ pub struct InitializeUser<'info> {
     )]
     pub user_profile: Account<'info, UserProfile>,
 
+    /// Registry entry for the normalized referrer code
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = ReferrerCode::LEN,
+        seeds = [crate::constants::REFERRER_CODE_SEED, ReferrerCode::normalize(&referrer_code)?.as_bytes()],
+        bump
+    )]
+    pub referrer_code_account: Account<'info, ReferrerCode>,
+
     pub system_program: Program<'info, System>,
 }
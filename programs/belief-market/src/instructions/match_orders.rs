@@ -0,0 +1,271 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
+use crate::math::fixed_point::{self, Rounding};
+use crate::state::{CostBasis, GlobalState, Market, OutcomeBook, Order, OrderSide};
+
+/// Event emitted when a bid and an ask are matched
+#[event]
+pub struct OrdersMatched {
+    pub market: Pubkey,
+    pub outcome_index: u8,
+    pub bid_id: u64,
+    pub ask_id: u64,
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub price: u64,
+    pub quantity: u64,
+    pub fee_amount: u64,
+}
+
+/// Match a crossing bid and ask for the same outcome
+/// Permissionless: anyone can crank the book. The fill executes at the price
+/// of the order that was resting first, and the trading fee is taken from the
+/// seller's proceeds into the market's accumulated fees.
+pub fn match_orders(ctx: Context<MatchOrders>) -> Result<()> {
+    require!(
+        !ctx.accounts.global_state.is_paused(),
+        crate::errors::BelievError::ProgramPaused
+    );
+
+    let market = &mut ctx.accounts.market;
+    let mut book = ctx.accounts.outcome_book.load_mut()?;
+    let bid = &mut ctx.accounts.bid;
+    let ask = &mut ctx.accounts.ask;
+
+    // Ensure market is open for trading
+    require!(
+        market.is_trading(Clock::get()?.unix_timestamp),
+        crate::errors::BelievError::TradingClosed
+    );
+
+    require!(
+        bid.side == OrderSide::Bid && ask.side == OrderSide::Ask,
+        crate::errors::BelievError::OrderMismatch
+    );
+    require!(
+        bid.outcome_index == ask.outcome_index,
+        crate::errors::BelievError::OrderMismatch
+    );
+    require!(
+        bid.is_open() && ask.is_open(),
+        crate::errors::BelievError::OrderNotOpen
+    );
+    require!(
+        bid.price >= ask.price,
+        crate::errors::BelievError::OrdersDoNotCross
+    );
+
+    let outcome_index = bid.outcome_index;
+    let quantity = bid.remaining.min(ask.remaining);
+    let price = if bid.order_id < ask.order_id { bid.price } else { ask.price };
+
+    let cost = fixed_point::mul_div_u64(
+        quantity,
+        price,
+        crate::constants::ORDER_PRICE_SCALE,
+        Rounding::Down,
+    )
+    .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+
+    let fee_amount = market.trading_fee(cost)?;
+    let seller_proceeds = cost
+        .checked_sub(fee_amount)
+        .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+
+    // Settle the fill against both escrows
+    bid.remaining -= quantity;
+    bid.escrow = bid.escrow
+        .checked_sub(cost)
+        .ok_or(crate::errors::BelievError::MarketCalculationError)?;
+    ask.remaining -= quantity;
+    ask.escrow -= quantity;
+
+    // Once the bid is filled, whatever it escrowed above the execution price goes back
+    let bid_refund = if bid.is_open() { 0 } else { bid.escrow };
+    bid.escrow -= bid_refund;
+
+    market.order_escrow = market.order_escrow
+        .checked_sub(cost)
+        .ok_or(crate::errors::BelievError::ArithmeticOverflow)?
+        .checked_sub(bid_refund)
+        .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+    market.ask_escrow = market.ask_escrow
+        .checked_sub(quantity)
+        .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+
+    market.accrue_fee(&mut book, outcome_index as usize, fee_amount)?;
+
+    // The buyer paid the full cost in and the seller took it out; a self-match
+    // leaves the owner's basis unchanged
+    if bid.owner != ask.owner {
+        market.add_cost_basis(&mut ctx.accounts.buyer_cost_basis, cost)?;
+        market.remove_cost_basis(&mut ctx.accounts.seller_cost_basis, cost);
+    }
+    let signer_seeds: &[&[u8]] = &[
+        crate::constants::MARKET_SEED,
+        &market.market_id.to_le_bytes(),
+        &[market.bump],
+    ];
+
+    // Mint the filled shares to the buyer; the ask burned them when it was placed
+    let mint_ix = MintTo {
+        mint: ctx.accounts.outcome_mint.to_account_info(),
+        to: ctx.accounts.buyer_outcome_account.to_account_info(),
+        authority: market.to_account_info(),
+    };
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            mint_ix,
+            &[signer_seeds],
+        ),
+        quantity,
+    )?;
+
+    // Pay the seller from the bid escrow held in the market vault
+    if seller_proceeds > 0 {
+        let transfer_ix = Transfer {
+            from: ctx.accounts.market_token_account.to_account_info(),
+            to: ctx.accounts.seller_token_account.to_account_info(),
+            authority: ctx.accounts.market_vault_authority.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                transfer_ix,
+                &[signer_seeds],
+            ),
+            seller_proceeds,
+        )?;
+    }
+
+    if bid_refund > 0 {
+        let transfer_ix = Transfer {
+            from: ctx.accounts.market_token_account.to_account_info(),
+            to: ctx.accounts.buyer_token_account.to_account_info(),
+            authority: ctx.accounts.market_vault_authority.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                transfer_ix,
+                &[signer_seeds],
+            ),
+            bid_refund,
+        )?;
+    }
+
+    emit!(OrdersMatched {
+        market: market.key(),
+        outcome_index,
+        bid_id: bid.order_id,
+        ask_id: ask.order_id,
+        buyer: bid.owner,
+        seller: ask.owner,
+        price,
+        quantity,
+        fee_amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MatchOrders<'info> {
+    pub matcher: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = bid.market == market.order_seed_address(market.key()) @ crate::errors::BelievError::OrderMismatch,
+        seeds = [crate::constants::ORDER_SEED, market.order_seed_address(market.key()).as_ref(), bid.order_id.to_le_bytes().as_ref()],
+        bump = bid.bump
+    )]
+    pub bid: Account<'info, Order>,
+
+    #[account(
+        mut,
+        constraint = ask.market == market.order_seed_address(market.key()) @ crate::errors::BelievError::OrderMismatch,
+        seeds = [crate::constants::ORDER_SEED, market.order_seed_address(market.key()).as_ref(), ask.order_id.to_le_bytes().as_ref()],
+        bump = ask.bump
+    )]
+    pub ask: Account<'info, Order>,
+
+    #[account(
+        mut,
+        address = market.outcome_mint(&*outcome_book.load()?, bid.outcome_index as usize)?
+    )]
+    pub outcome_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = outcome_mint,
+        associated_token::authority = bid.owner
+    )]
+    pub buyer_outcome_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = market.collateral_mint,
+        token::authority = bid.owner
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = market.collateral_mint,
+        token::authority = ask.owner
+    )]
+    pub seller_token_account: Account<'info, TokenAccount>,
+
+    /// Created when the bid was placed
+    #[account(
+        mut,
+        seeds = [crate::constants::COST_BASIS_SEED, market.key().as_ref(), bid.owner.as_ref()],
+        bump = buyer_cost_basis.bump
+    )]
+    pub buyer_cost_basis: Account<'info, CostBasis>,
+
+    /// Created when the ask was placed
+    #[account(
+        mut,
+        seeds = [crate::constants::COST_BASIS_SEED, market.key().as_ref(), ask.owner.as_ref()],
+        bump = seller_cost_basis.bump
+    )]
+    pub seller_cost_basis: Account<'info, CostBasis>,
+
+    /// Market vault holding the collateral
+    #[account(
+        mut,
+        address = market.vault @ crate::errors::BelievError::InvalidVault,
+        token::authority = market
+    )]
+    pub market_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        address = market.outcome_book
+    )]
+    pub outcome_book: AccountLoader<'info, OutcomeBook>,
+
+    /// Market PDA used as authority for CPI
+    #[account(
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market_vault_authority: AccountInfo<'info>,
+
+    #[account(
+        seeds = [crate::constants::GLOBAL_STATE_SEED],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    pub token_program: Program<'info, Token>,
+}
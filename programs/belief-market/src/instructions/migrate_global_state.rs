@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use crate::state::{GlobalState, LegacyGlobalState};
+
+/// Event emitted when the global state moves to the current layout
+#[event]
+pub struct GlobalStateMigrated {
+    pub authority: Pubkey,
+    pub platform_wallet: Pubkey,
+}
+
+/// Grow the global state written by the original program to the current layout - only authority
+/// Keeps the authority, platform wallet and pause flag. The collateral allowlist
+/// starts empty, the dispute configuration takes its defaults with the authority
+/// as arbiter, and the market counter starts at zero; legacy markets take their
+/// IDs as they are migrated. The authority pays the extra rent.
+pub fn migrate_global_state(ctx: Context<MigrateGlobalState>) -> Result<()> {
+    let global_info = ctx.accounts.global_state.to_account_info();
+    let legacy_global_state = LegacyGlobalState::load(&global_info)?;
+    require!(
+        ctx.accounts.authority.key() == legacy_global_state.authority,
+        crate::errors::BelievError::Unauthorized
+    );
+
+    // Top up rent for the larger account
+    let rent_due = Rent::get()?
+        .minimum_balance(GlobalState::LEN)
+        .saturating_sub(global_info.lamports());
+    if rent_due > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: global_info.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+    global_info.realloc(GlobalState::LEN, false)?;
+
+    let global_state = GlobalState {
+        authority: legacy_global_state.authority,
+        platform_wallet: legacy_global_state.platform_wallet,
+        paused: legacy_global_state.paused,
+        collateral_mints: Vec::new(),
+        dispute_period_secs: crate::constants::DEFAULT_DISPUTE_PERIOD_SECS,
+        dispute_bond: crate::constants::DEFAULT_DISPUTE_BOND,
+        arbiter: legacy_global_state.authority,
+        market_count: 0,
+        bump: ctx.bumps.global_state,
+    };
+    global_state.try_serialize(&mut &mut global_info.try_borrow_mut_data()?[..])?;
+
+    emit!(GlobalStateMigrated {
+        authority: global_state.authority,
+        platform_wallet: global_state.platform_wallet,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateGlobalState<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Global state in the legacy layout; its owner, discriminator and size
+    /// are verified in the handler
+    #[account(
+        mut,
+        seeds = [crate::constants::GLOBAL_STATE_SEED],
+        bump
+    )]
+    pub global_state: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,321 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, CreateAccount};
+use anchor_spl::associated_token::{self, get_associated_token_address, AssociatedToken};
+use anchor_spl::token::{self, CloseAccount, InitializeMint2, Mint, MintTo, Token, TokenAccount, Transfer};
+use crate::state::{CostBasis, GlobalState, LegacyMarket, Market, MarketStatus, OutcomeBook, PricingMode};
+
+/// Event emitted when a legacy market moves to its ID-seeded address
+#[event]
+pub struct MarketMigrated {
+    pub legacy_market: Pubkey,
+    pub market: Pubkey,
+    pub market_id: u64,
+}
+
+/// Move a market created by the original program under the legacy
+/// `[MARKET_SEED, creator, resolve_at]` seeds to `[MARKET_SEED, market_id]` - only authority
+/// Assigns the next market ID and rebuilds the market in the current layout as
+/// a parimutuel market resolved by the global authority. Its per-outcome state
+/// moves into `outcome_book`, which the client allocates beforehand as for
+/// `create_market`. The collateral moves from the legacy market's token account
+/// into a new vault PDA, the legacy token account and market account are closed,
+/// and an outcome share mint is created for every outcome. A resolved market is
+/// finalized with everything paid to its winner; any other market opens.
+/// The original program recorded no share holders, so each outcome's outstanding
+/// shares are minted to the authority's associated token account, to be
+/// distributed to their holders from the original purchase history. The
+/// authority is likewise credited the whole pool as its cost basis, so it can
+/// claim the holders' refunds if the market is later voided.
+/// Remaining accounts: `[mint_0, authority_account_0, mint_1, ...]` in outcome
+/// order, where `mint_i` is the `[OUTCOME_MINT_SEED, market, i]` PDA and
+/// `authority_account_i` the authority's associated token account for it
+pub fn migrate_market<'info>(
+    ctx: Context<'_, '_, '_, 'info, MigrateMarket<'info>>,
+) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.global_state.authority,
+        crate::errors::BelievError::Unauthorized
+    );
+
+    let legacy_info = ctx.accounts.legacy_market.to_account_info();
+    let legacy_key = legacy_info.key();
+    let new_market_key = ctx.accounts.market.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    let legacy_market = LegacyMarket::load(&legacy_info)?;
+    let num_outcomes = legacy_market.num_outcomes as usize;
+    require!(
+        ctx.remaining_accounts.len() == 2 * num_outcomes,
+        crate::errors::BelievError::InvalidTokenMint
+    );
+
+    let resolve_at_bytes = legacy_market.resolve_at.to_le_bytes();
+    let legacy_seeds: &[&[u8]] = &[
+        crate::constants::MARKET_SEED,
+        legacy_market.creator.as_ref(),
+        &resolve_at_bytes,
+        &[legacy_market.bump],
+    ];
+    let legacy_address = Pubkey::create_program_address(legacy_seeds, &crate::ID)
+        .map_err(|_| crate::errors::BelievError::InvalidMarketState)?;
+    require!(
+        legacy_address == legacy_key,
+        crate::errors::BelievError::InvalidMarketState
+    );
+
+    // Move the collateral into the new vault and close the legacy token account
+    let transfer_ix = Transfer {
+        from: ctx.accounts.legacy_token_account.to_account_info(),
+        to: ctx.accounts.market_token_account.to_account_info(),
+        authority: legacy_info.clone(),
+    };
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            transfer_ix,
+            &[legacy_seeds],
+        ),
+        ctx.accounts.legacy_token_account.amount,
+    )?;
+    let close_ix = CloseAccount {
+        account: ctx.accounts.legacy_token_account.to_account_info(),
+        destination: ctx.accounts.authority.to_account_info(),
+        authority: legacy_info.clone(),
+    };
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        close_ix,
+        &[legacy_seeds],
+    ))?;
+
+    let market_id = ctx.accounts.global_state.next_market_id()?;
+    let market_id_bytes = market_id.to_le_bytes();
+    let market_seeds: &[&[u8]] = &[
+        crate::constants::MARKET_SEED,
+        &market_id_bytes,
+        &[ctx.bumps.market],
+    ];
+    let decimals = ctx.accounts.collateral_mint.decimals;
+    let mint_rent = Rent::get()?.minimum_balance(Mint::LEN);
+
+    let mut book = ctx.accounts.outcome_book.load_init()?;
+    book.market = new_market_key;
+    book.num_outcomes = legacy_market.num_outcomes as u16;
+    for (i, label) in legacy_market.outcome_labels.iter().enumerate() {
+        book.set_label(i, label)?;
+    }
+    book.outcome_pools[..num_outcomes].copy_from_slice(&legacy_market.outcome_pools);
+    book.outcome_shares[..num_outcomes].copy_from_slice(&legacy_market.outcome_shares);
+    book.accumulated_fees[..num_outcomes].copy_from_slice(&legacy_market.accumulated_fees);
+
+    // Create each outcome's share mint and mint its outstanding shares to the authority
+    for (i, chunk) in ctx.remaining_accounts.chunks_exact(2).enumerate() {
+        let (mint_info, authority_account) = (&chunk[0], &chunk[1]);
+        let outcome_index = [i as u8];
+        let (mint, mint_bump) = Pubkey::find_program_address(
+            &[crate::constants::OUTCOME_MINT_SEED, new_market_key.as_ref(), &outcome_index],
+            &crate::ID,
+        );
+        require!(
+            mint_info.key() == mint
+                && authority_account.key()
+                    == get_associated_token_address(&ctx.accounts.authority.key(), &mint),
+            crate::errors::BelievError::InvalidTokenMint
+        );
+
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                CreateAccount {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: mint_info.clone(),
+                },
+                &[&[crate::constants::OUTCOME_MINT_SEED, new_market_key.as_ref(), &outcome_index, &[mint_bump]]],
+            ),
+            mint_rent,
+            Mint::LEN as u64,
+            &token::ID,
+        )?;
+        token::initialize_mint2(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                InitializeMint2 { mint: mint_info.clone() },
+            ),
+            decimals,
+            &new_market_key,
+            None,
+        )?;
+        book.outcome_mints[i] = mint;
+
+        let outstanding = legacy_market.outcome_shares[i];
+        if outstanding > 0 {
+            associated_token::create(CpiContext::new(
+                ctx.accounts.associated_token_program.to_account_info(),
+                associated_token::Create {
+                    payer: ctx.accounts.authority.to_account_info(),
+                    associated_token: authority_account.clone(),
+                    authority: ctx.accounts.authority.to_account_info(),
+                    mint: mint_info.clone(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                },
+            ))?;
+            token::mint_to(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    MintTo {
+                        mint: mint_info.clone(),
+                        to: authority_account.clone(),
+                        authority: ctx.accounts.market.to_account_info(),
+                    },
+                    &[market_seeds],
+                ),
+                outstanding,
+            )?;
+        }
+    }
+
+    let payout_numerators = legacy_market.payout_numerators()?;
+    let (status, dispute_ends_at) = if payout_numerators.is_some() {
+        (MarketStatus::Finalized, now)
+    } else {
+        (MarketStatus::Open, 0)
+    };
+    ctx.accounts.market.set_inner(Market {
+        market_id,
+        legacy_address: Some(legacy_key),
+        creator: legacy_market.creator,
+        invitor: legacy_market.invitor,
+        referrer: legacy_market.referrer,
+        collateral_mint: ctx.accounts.collateral_mint.key(),
+        collateral_decimals: decimals,
+        vault: ctx.accounts.market_token_account.key(),
+        outcome_book: ctx.accounts.outcome_book.key(),
+        resolver: ctx.accounts.global_state.authority,
+        num_outcomes: legacy_market.num_outcomes,
+        tags: legacy_market.tags,
+        price_bands: None,
+        publisher: None,
+        trading_fee_bps: legacy_market.trading_fee_bps,
+        pricing_mode: PricingMode::Parimutuel,
+        liquidity_param: 0,
+        liquidity_amount: 0,
+        resolve_at: legacy_market.resolve_at,
+        resolution_deadline: legacy_market.resolve_at
+            .checked_add(crate::constants::RESOLUTION_GRACE_PERIOD_SECS)
+            .ok_or(crate::errors::BelievError::ArithmeticOverflow)?,
+        status,
+        payout_denominator: 0,
+        dispute_ends_at,
+        attested_at: 0,
+        challenger: None,
+        challenge_bond: 0,
+        proposer: None,
+        proposal_bond: 0,
+        creator_peg_amount: legacy_market.creator_peg_amount,
+        creator_peg_claimed: legacy_market.creator_peg_claimed,
+        void_reason: None,
+        next_order_id: 0,
+        order_escrow: 0,
+        ask_escrow: 0,
+        total_cost_basis: 0,
+        created_at: legacy_market.created_at,
+        bump: ctx.bumps.market,
+    });
+    if let Some(payout_numerators) = payout_numerators {
+        ctx.accounts.market.resolve(&mut book, &payout_numerators, 1)?;
+    }
+
+    let cost_basis = &mut ctx.accounts.authority_cost_basis;
+    cost_basis.init_if_new(new_market_key, ctx.accounts.authority.key(), ctx.bumps.authority_cost_basis);
+    ctx.accounts.market.add_cost_basis(cost_basis, book.total_pool())?;
+
+    // Close the legacy account, returning its rent to the authority
+    let authority_info = ctx.accounts.authority.to_account_info();
+    **authority_info.try_borrow_mut_lamports()? = authority_info
+        .lamports()
+        .checked_add(legacy_info.lamports())
+        .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+    **legacy_info.try_borrow_mut_lamports()? = 0;
+    legacy_info.assign(&System::id());
+    legacy_info.realloc(0, false)?;
+
+    emit!(MarketMigrated {
+        legacy_market: legacy_key,
+        market: new_market_key,
+        market_id,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateMarket<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [crate::constants::GLOBAL_STATE_SEED],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    /// CHECK: Market under the legacy seeds and layout; its owner, discriminator
+    /// and address are verified in the handler
+    #[account(mut)]
+    pub legacy_market: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = LegacyMarket::load(&legacy_market)?.market_space(),
+        seeds = [crate::constants::MARKET_SEED, (global_state.market_count + 1).to_le_bytes().as_ref()],
+        bump
+    )]
+    pub market: Account<'info, Market>,
+
+    /// Per-outcome state of the migrated market, allocated by the client (`OutcomeBook::LEN` bytes)
+    #[account(zero)]
+    pub outcome_book: AccountLoader<'info, OutcomeBook>,
+
+    /// Mint the legacy market was denominated in; must be on the global allowlist
+    #[account(
+        constraint = global_state.is_collateral_allowed(&collateral_mint.key())
+            @ crate::errors::BelievError::CollateralMintNotAllowed
+    )]
+    pub collateral_mint: Account<'info, Mint>,
+
+    /// Token account holding the legacy market's collateral; closed by the migration
+    #[account(
+        mut,
+        token::mint = collateral_mint,
+        token::authority = legacy_market
+    )]
+    pub legacy_token_account: Account<'info, TokenAccount>,
+
+    /// Market vault holding the collateral, owned by the new market PDA
+    #[account(
+        init,
+        payer = authority,
+        token::mint = collateral_mint,
+        token::authority = market,
+        seeds = [crate::constants::VAULT_SEED, market.key().as_ref()],
+        bump
+    )]
+    pub market_token_account: Account<'info, TokenAccount>,
+
+    /// The authority's cost basis in the migrated market, held for the original holders
+    #[account(
+        init,
+        payer = authority,
+        space = CostBasis::LEN,
+        seeds = [crate::constants::COST_BASIS_SEED, market.key().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub authority_cost_basis: Account<'info, CostBasis>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -1,25 +1,83 @@
 pub mod initialize_global;
 pub mod initialize_user;
+pub mod backfill_referrer_code;
 pub mod create_market;
+pub mod update_market_tags;
+pub mod set_outcome_labels;
+pub mod migrate_market;
+pub mod migrate_global_state;
 pub mod buy_outcome;
 pub mod sell_outcome;
 pub mod resolve_market;
+pub mod close_trading;
+pub mod finalize_market;
 pub mod redeem_winnings;
 pub mod claim_peg;
 pub mod withdraw_fees;
+pub mod close_market;
 pub mod emergency_pause;
 pub mod emergency_unpause;
 pub mod view_functions;
+pub mod place_order;
+pub mod cancel_order;
+pub mod match_orders;
+pub mod mint_complete_set;
+pub mod burn_complete_set;
+pub mod initialize_outcome_mint;
+pub mod add_collateral_mint;
+pub mod remove_collateral_mint;
+pub mod void_market;
+pub mod void_expired_market;
+pub mod claim_refund;
+pub mod set_dispute_config;
+pub mod challenge_resolution;
+pub mod resolve_dispute;
+pub mod propose_outcome;
+pub mod settle_proposal;
+pub mod resolve_from_price_feed;
+pub mod resolve_from_attestation;
+pub mod create_resolver_council;
+pub mod submit_resolution_vote;
+
+mod outcome_tokens;
 
 pub use initialize_global::*;
 pub use initialize_user::*;
+pub use backfill_referrer_code::*;
 pub use create_market::*;
+pub use update_market_tags::*;
+pub use set_outcome_labels::*;
+pub use migrate_market::*;
+pub use migrate_global_state::*;
 pub use buy_outcome::*;
 pub use sell_outcome::*;
 pub use resolve_market::*;
+pub use close_trading::*;
+pub use finalize_market::*;
 pub use redeem_winnings::*;
 pub use claim_peg::*;
 pub use withdraw_fees::*;
+pub use close_market::*;
 pub use emergency_pause::*;
 pub use emergency_unpause::*;
 pub use view_functions::*;
+pub use place_order::*;
+pub use cancel_order::*;
+pub use match_orders::*;
+pub use mint_complete_set::*;
+pub use burn_complete_set::*;
+pub use initialize_outcome_mint::*;
+pub use add_collateral_mint::*;
+pub use remove_collateral_mint::*;
+pub use void_market::*;
+pub use void_expired_market::*;
+pub use claim_refund::*;
+pub use set_dispute_config::*;
+pub use challenge_resolution::*;
+pub use resolve_dispute::*;
+pub use propose_outcome::*;
+pub use settle_proposal::*;
+pub use resolve_from_price_feed::*;
+pub use resolve_from_attestation::*;
+pub use create_resolver_council::*;
+pub use submit_resolution_vote::*;
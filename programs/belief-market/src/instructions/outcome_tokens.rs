@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, TokenAccount};
+use crate::state::{Market, OutcomeBook};
+
+/// Deserialize `info` as a token account holding `mint` shares owned by `owner`
+pub(crate) fn load_outcome_token_account(
+    info: &AccountInfo,
+    mint: &Pubkey,
+    owner: &Pubkey,
+) -> Result<TokenAccount> {
+    require!(
+        info.owner == &token::ID,
+        crate::errors::BelievError::InvalidOutcomeTokenAccount
+    );
+    let account = TokenAccount::try_deserialize(&mut &info.data.borrow()[..])?;
+    require!(
+        account.mint == *mint && account.owner == *owner,
+        crate::errors::BelievError::InvalidOutcomeTokenAccount
+    );
+    Ok(account)
+}
+
+/// Split `remaining_accounts` into one (outcome mint, owner token account) pair per outcome
+/// Expects `[mint_0, account_0, mint_1, account_1, ...]` in outcome order
+pub(crate) fn outcome_token_pairs<'a, 'info>(
+    market: &Market,
+    book: &OutcomeBook,
+    owner: &Pubkey,
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> Result<Vec<(&'a AccountInfo<'info>, &'a AccountInfo<'info>)>> {
+    require!(
+        remaining_accounts.len() == 2 * market.num_outcomes as usize,
+        crate::errors::BelievError::InvalidOutcomeTokenAccount
+    );
+
+    let mut pairs = Vec::with_capacity(market.num_outcomes as usize);
+    for (i, chunk) in remaining_accounts.chunks_exact(2).enumerate() {
+        let mint = market.outcome_mint(book, i)?;
+        require!(
+            chunk[0].key() == mint,
+            crate::errors::BelievError::InvalidOutcomeTokenAccount
+        );
+        load_outcome_token_account(&chunk[1], &mint, owner)?;
+        pairs.push((&chunk[0], &chunk[1]));
+    }
+
+    Ok(pairs)
+}
@@ -0,0 +1,226 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+use crate::math::fixed_point::{self, Rounding};
+use crate::state::{CostBasis, GlobalState, Market, OutcomeBook, Order, OrderSide};
+
+/// Event emitted when a limit order is placed
+#[event]
+pub struct OrderPlaced {
+    pub market: Pubkey,
+    pub owner: Pubkey,
+    pub order_id: u64,
+    pub outcome_index: u8,
+    pub side: OrderSide,
+    pub price: u64,
+    pub quantity: u64,
+}
+
+/// Place a limit order for shares of an outcome
+/// Bids escrow `quantity * price` collateral in the market vault,
+/// asks escrow `quantity` shares by burning them from the owner's outcome token account
+pub fn place_order(
+    ctx: Context<PlaceOrder>,
+    outcome_index: u8,
+    side: OrderSide,
+    price: u64,
+    quantity: u64,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.global_state.is_paused(),
+        crate::errors::BelievError::ProgramPaused
+    );
+    require!(
+        quantity > 0,
+        crate::errors::BelievError::InvalidAmount
+    );
+    require!(
+        price > 0,
+        crate::errors::BelievError::InvalidOrderPrice
+    );
+
+    let market = &mut ctx.accounts.market;
+
+    // Validate outcome index
+    require!(
+        (outcome_index as usize) < market.num_outcomes as usize,
+        crate::errors::BelievError::InvalidOutcomeIndex
+    );
+
+    // Ensure market is open for trading
+    require!(
+        market.is_trading(Clock::get()?.unix_timestamp),
+        crate::errors::BelievError::TradingClosed
+    );
+
+    let escrow = match side {
+        OrderSide::Bid => {
+            // Escrow the full cost at the limit price, rounded up
+            let cost = fixed_point::mul_div_u64(
+                quantity,
+                price,
+                crate::constants::ORDER_PRICE_SCALE,
+                Rounding::Up,
+            )
+            .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+            require!(
+                cost > 0,
+                crate::errors::BelievError::InvalidAmount
+            );
+
+            let transfer_ix = Transfer {
+                from: ctx.accounts.owner_token_account.to_account_info(),
+                to: ctx.accounts.market_token_account.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            };
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    transfer_ix,
+                ),
+                cost,
+            )?;
+
+            market.order_escrow = market.order_escrow
+                .checked_add(cost)
+                .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+            cost
+        }
+        OrderSide::Ask => {
+            require!(
+                ctx.accounts.owner_outcome_account.amount >= quantity,
+                crate::errors::BelievError::InsufficientShares
+            );
+
+            // Escrowed shares are burned and re-minted on fill or cancel
+            let burn_ix = Burn {
+                mint: ctx.accounts.outcome_mint.to_account_info(),
+                from: ctx.accounts.owner_outcome_account.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            };
+            token::burn(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    burn_ix,
+                ),
+                quantity,
+            )?;
+
+            market.ask_escrow = market.ask_escrow
+                .checked_add(quantity)
+                .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+            quantity
+        }
+    };
+
+    let order_id = market.next_order_id;
+    market.next_order_id = market.next_order_id
+        .checked_add(1)
+        .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+
+    let order = &mut ctx.accounts.order;
+    order.market = market.order_seed_address(market.key());
+    order.owner = ctx.accounts.owner.key();
+    order.order_id = order_id;
+    order.outcome_index = outcome_index;
+    order.side = side;
+    order.price = price;
+    order.quantity = quantity;
+    order.remaining = quantity;
+    order.escrow = escrow;
+    order.created_at = Clock::get()?.unix_timestamp;
+    order.bump = ctx.bumps.order;
+
+    // Fills settle against the owner's cost basis, so make sure it exists
+    ctx.accounts.owner_cost_basis.init_if_new(market.key(), order.owner, ctx.bumps.owner_cost_basis);
+
+    emit!(OrderPlaced {
+        market: market.key(),
+        owner: order.owner,
+        order_id,
+        outcome_index,
+        side,
+        price,
+        quantity,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(outcome_index: u8, side: OrderSide, price: u64, quantity: u64)]
+pub struct PlaceOrder<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = market.collateral_mint,
+        token::authority = owner
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    /// Market vault holding the collateral
+    #[account(
+        mut,
+        address = market.vault @ crate::errors::BelievError::InvalidVault,
+        token::authority = market
+    )]
+    pub market_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        address = market.outcome_book
+    )]
+    pub outcome_book: AccountLoader<'info, OutcomeBook>,
+
+    #[account(
+        mut,
+        address = market.outcome_mint(&*outcome_book.load()?, outcome_index as usize)?
+    )]
+    pub outcome_mint: Account<'info, Mint>,
+
+    /// Created on first use so fills can be minted into it
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = outcome_mint,
+        associated_token::authority = owner
+    )]
+    pub owner_outcome_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = Order::LEN,
+        seeds = [crate::constants::ORDER_SEED, market.order_seed_address(market.key()).as_ref(), market.next_order_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub order: Account<'info, Order>,
+
+    /// The owner's net cost in this market, refunded if it is voided
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = CostBasis::LEN,
+        seeds = [crate::constants::COST_BASIS_SEED, market.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub owner_cost_basis: Account<'info, CostBasis>,
+
+    #[account(
+        seeds = [crate::constants::GLOBAL_STATE_SEED],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{GlobalState, Market, MarketStatus, OutcomeBook};
+
+/// Event emitted when an outcome is proposed
+#[event]
+pub struct OutcomeProposed {
+    pub market: Pubkey,
+    pub proposer: Pubkey,
+    pub payout_numerators: Vec<u32>,
+    pub payout_denominator: u32,
+    pub bond: u64,
+    pub liveness_ends_at: i64,
+}
+
+/// Propose a market's outcome once its resolution time has passed
+/// Permissionless for markets resolved by the global authority; markets with a
+/// designated resolver or council are resolved only by it. The proposer posts
+/// the dispute bond and the market resolves tentatively. Anyone can challenge it with `challenge_resolution` during the
+/// liveness period (the configured dispute period); otherwise anyone can
+/// `settle_proposal` afterwards.
+pub fn propose_outcome(
+    ctx: Context<ProposeOutcome>,
+    payout_numerators: Vec<u32>,
+    payout_denominator: u32,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.global_state.is_paused(),
+        crate::errors::BelievError::ProgramPaused
+    );
+
+    let market = &mut ctx.accounts.market;
+    let mut book = ctx.accounts.outcome_book.load_mut()?;
+
+    // Check resolution time has passed
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now >= market.resolve_at,
+        crate::errors::BelievError::ResolutionTimeNotReached
+    );
+
+    // Price-band markets can only be resolved from their feed
+    require!(
+        market.price_bands.is_none(),
+        crate::errors::BelievError::ResolvesFromPriceFeed
+    );
+
+    // Publisher markets can only be resolved from an attestation
+    require!(
+        market.publisher.is_none(),
+        crate::errors::BelievError::ResolvesFromPublisher
+    );
+
+    // Markets with a designated resolver or council are resolved only by it
+    require!(
+        market.resolver == ctx.accounts.global_state.authority,
+        crate::errors::BelievError::ResolvesFromResolver
+    );
+
+    let market_key = market.key();
+    market.set_status(market_key, MarketStatus::Proposed, now)?;
+    market.resolve(&mut book, &payout_numerators, payout_denominator)?;
+    market.start_dispute_window(now, ctx.accounts.global_state.dispute_period_secs)?;
+
+    let bond = ctx.accounts.global_state.dispute_bond_amount(market.collateral_decimals)?;
+
+    // Transfer bond from proposer to market vault
+    if bond > 0 {
+        let transfer_ix = Transfer {
+            from: ctx.accounts.proposer_token_account.to_account_info(),
+            to: ctx.accounts.market_token_account.to_account_info(),
+            authority: ctx.accounts.proposer.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                transfer_ix,
+            ),
+            bond,
+        )?;
+    }
+
+    market.proposer = Some(ctx.accounts.proposer.key());
+    market.proposal_bond = bond;
+
+    emit!(OutcomeProposed {
+        market: market.key(),
+        proposer: ctx.accounts.proposer.key(),
+        payout_numerators: book.numerators().to_vec(),
+        payout_denominator,
+        bond,
+        liveness_ends_at: market.dispute_ends_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeOutcome<'info> {
+    pub proposer: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = market.collateral_mint,
+        token::authority = proposer
+    )]
+    pub proposer_token_account: Account<'info, TokenAccount>,
+
+    /// Market vault holding the collateral
+    #[account(
+        mut,
+        address = market.vault @ crate::errors::BelievError::InvalidVault,
+        token::authority = market
+    )]
+    pub market_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        address = market.outcome_book
+    )]
+    pub outcome_book: AccountLoader<'info, OutcomeBook>,
+
+    #[account(
+        seeds = [crate::constants::GLOBAL_STATE_SEED],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    pub token_program: Program<'info, Token>,
+}
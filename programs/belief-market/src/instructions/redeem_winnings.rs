@@ -1,51 +1,55 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::{GlobalState, Market};
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+use crate::state::{GlobalState, Market, OutcomeBook};
 
 /// Event emitted when a user redeems winnings
 #[event]
 pub struct WinningsRedeemed {
     pub market: Pubkey,
     pub winner: Pubkey,
+    pub outcome_index: u8,
     pub amount_redeemed: u64,
 }
 
 /// Redeem winnings for resolved markets
-/// User must have shares in the winning outcome
-pub fn redeem_winnings(
-    ctx: Context<RedeemWinnings>,
-    winning_shares: u64,
-) -> Result<()> {
+/// Burns every token of `outcome_index` in the caller's token account and pays
+/// the outcome's share of the payout vector
+pub fn redeem_winnings(ctx: Context<RedeemWinnings>, outcome_index: u8) -> Result<()> {
     require!(
         !ctx.accounts.global_state.is_paused(),
         crate::errors::BelievError::ProgramPaused
     );
 
     let market = &mut ctx.accounts.market;
+    let mut book = ctx.accounts.outcome_book.load_mut()?;
 
     // Ensure market is resolved
     require!(
-        market.resolved,
+        !market.status.is_unresolved(),
         crate::errors::BelievError::MarketNotResolved
     );
 
-    let winning_outcome = market.winning_outcome
-        .ok_or(crate::errors::BelievError::InvalidMarketState)?;
+    // Ensure the resolution can no longer be challenged
+    let market_key = market.key();
+    market.finalize_if_due(market_key, Clock::get()?.unix_timestamp)?;
+    require!(
+        market.status.is_settled(),
+        crate::errors::BelievError::ResolutionNotFinal
+    );
 
+    // Voided markets pay refunds instead
     require!(
-        winning_shares > 0,
-        crate::errors::BelievError::InvalidAmount
+        !market.is_voided(),
+        crate::errors::BelievError::MarketVoided
     );
 
-    // Calculate redemption value: shares * (total_pool / total_winning_shares)
-    // This ensures: money_in = money_out at all times
-    let redemption_per_share = market.total_pool()
-        .checked_div(market.outcome_shares[winning_outcome as usize])
-        .ok_or(crate::errors::BelievError::MarketCalculationError)?;
+    let winning_shares = ctx.accounts.winner_outcome_account.amount;
+    require!(
+        winning_shares > 0,
+        crate::errors::BelievError::NoWinningsToRedeem
+    );
 
-    let amount_to_redeem = winning_shares
-        .checked_mul(redemption_per_share)
-        .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+    let amount_to_redeem = market.quote_redemption(&book, outcome_index as usize, winning_shares)?;
 
     require!(
         amount_to_redeem > 0,
@@ -64,60 +68,84 @@ pub fn redeem_winnings(
             transfer_ix,
             &[&[
                 crate::constants::MARKET_SEED,
-                market.creator.as_ref(),
-                market.resolve_at.to_le_bytes().as_ref(),
+                market.market_id.to_le_bytes().as_ref(),
                 &[market.bump],
             ]],
         ),
         amount_to_redeem,
     )?;
 
-    // Reduce outcome shares and pools
-    market.outcome_shares[winning_outcome as usize] = market.outcome_shares[winning_outcome as usize]
+    // Reduce outcome shares, the outcome's reserved payout and pools
+    book.outcome_shares[outcome_index as usize] = book.outcome_shares[outcome_index as usize]
         .checked_sub(winning_shares)
         .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
 
-    market.outcome_pools[winning_outcome as usize] = market.outcome_pools[winning_outcome as usize]
+    book.payout_pools[outcome_index as usize] = book.payout_pools[outcome_index as usize]
         .checked_sub(amount_to_redeem)
         .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
 
+    market.withdraw_from_pools(&mut book, outcome_index as usize, amount_to_redeem)?;
+
+    // Burn the redeemed outcome tokens
+    let burn_ix = Burn {
+        mint: ctx.accounts.outcome_mint.to_account_info(),
+        from: ctx.accounts.winner_outcome_account.to_account_info(),
+        authority: ctx.accounts.winner.to_account_info(),
+    };
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            burn_ix,
+        ),
+        winning_shares,
+    )?;
+
     emit!(WinningsRedeemed {
         market: market.key(),
         winner: ctx.accounts.winner.key(),
-        amount_redeemed,
+        outcome_index,
+        amount_redeemed: amount_to_redeem,
     });
 
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(winning_shares: u64)]
+#[instruction(outcome_index: u8)]
 pub struct RedeemWinnings<'info> {
     pub winner: Signer<'info>,
 
     #[account(
         mut,
-        token::mint = crate::constants::USDC_MINT,
+        token::mint = market.collateral_mint,
         token::authority = winner
     )]
     pub winner_token_account: Account<'info, TokenAccount>,
 
+    /// Market vault holding the collateral
     #[account(
         mut,
-        token::mint = crate::constants::USDC_MINT,
+        address = market.vault @ crate::errors::BelievError::InvalidVault,
+        token::authority = market
     )]
     pub market_token_account: Account<'info, TokenAccount>,
 
     #[account(
         mut,
-        seeds = [crate::constants::MARKET_SEED, market.creator.as_ref(), market.resolve_at.to_le_bytes().as_ref()],
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
         bump = market.bump
     )]
     pub market: Account<'info, Market>,
 
+    #[account(
+        mut,
+        address = market.outcome_book
+    )]
+    pub outcome_book: AccountLoader<'info, OutcomeBook>,
+
     /// Market PDA used as authority for CPI
     #[account(
-        seeds = [crate::constants::MARKET_SEED, market.creator.as_ref(), market.resolve_at.to_le_bytes().as_ref()],
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
         bump = market.bump
     )]
     pub market_vault_authority: AccountInfo<'info>,
@@ -128,5 +156,18 @@ pub struct RedeemWinnings<'info> {
     )]
     pub global_state: Account<'info, GlobalState>,
 
+    #[account(
+        mut,
+        address = market.outcome_mint(&*outcome_book.load()?, outcome_index as usize)?
+    )]
+    pub outcome_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = outcome_mint,
+        token::authority = winner
+    )]
+    pub winner_outcome_account: Account<'info, TokenAccount>,
+
     pub token_program: Program<'info, Token>,
 }
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::state::GlobalState;
+
+/// Event emitted when a collateral mint is removed from the allowlist
+#[event]
+pub struct CollateralMintRemoved {
+    pub mint: Pubkey,
+}
+
+/// Stop new markets from using `mint` as collateral - only authority can call
+/// Existing markets keep trading in the mint they were created with
+pub fn remove_collateral_mint(ctx: Context<RemoveCollateralMint>, mint: Pubkey) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.global_state.authority,
+        crate::errors::BelievError::Unauthorized
+    );
+
+    let global_state = &mut ctx.accounts.global_state;
+
+    require!(
+        global_state.is_collateral_allowed(&mint),
+        crate::errors::BelievError::CollateralMintNotAllowed
+    );
+
+    global_state.collateral_mints.retain(|allowed| *allowed != mint);
+
+    emit!(CollateralMintRemoved { mint });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveCollateralMint<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [crate::constants::GLOBAL_STATE_SEED],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+}
@@ -0,0 +1,155 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{GlobalState, Market, MarketStatus, OutcomeBook};
+
+/// Event emitted when the arbiter rules on a challenged resolution
+#[event]
+pub struct DisputeResolved {
+    pub market: Pubkey,
+    pub challenger: Pubkey,
+    pub overturned: bool,
+    pub payout_numerators: Vec<u32>,
+    pub payout_denominator: u32,
+}
+
+/// Rule on a challenged resolution - only the arbiter can call
+/// Passing `payout_numerators` overturns the resolution with the new payout vector
+/// and returns the challenger's bond, along with the bond of the proposer whose
+/// outcome was overturned; passing `None` confirms it and slashes the challenger's
+/// bond to the platform. Either way the ruling ends the dispute window.
+pub fn resolve_dispute(
+    ctx: Context<ResolveDispute>,
+    payout_numerators: Option<Vec<u32>>,
+    payout_denominator: u32,
+) -> Result<()> {
+    require!(
+        ctx.accounts.arbiter.key() == ctx.accounts.global_state.arbiter,
+        crate::errors::BelievError::Unauthorized
+    );
+
+    let market = &mut ctx.accounts.market;
+    let mut book = ctx.accounts.outcome_book.load_mut()?;
+
+    let challenger = market.challenger
+        .ok_or(crate::errors::BelievError::NotDisputed)?;
+
+    let overturned = payout_numerators.is_some();
+    if let Some(payout_numerators) = payout_numerators {
+        // No payouts can have happened while the challenge was open
+        market.resolve(&mut book, &payout_numerators, payout_denominator)?;
+    }
+
+    let mut bond = market.challenge_bond;
+    if overturned && market.proposer.is_some() {
+        // The proposer's bond is forfeited to the challenger
+        bond = bond
+            .checked_add(market.proposal_bond)
+            .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+        market.proposer = None;
+        market.proposal_bond = 0;
+    }
+    if bond > 0 {
+        let destination = if overturned {
+            ctx.accounts.challenger_token_account.to_account_info()
+        } else {
+            ctx.accounts.platform_token_account.to_account_info()
+        };
+        let transfer_ix = Transfer {
+            from: ctx.accounts.market_token_account.to_account_info(),
+            to: destination,
+            authority: ctx.accounts.market_vault_authority.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                transfer_ix,
+                &[&[
+                    crate::constants::MARKET_SEED,
+                    market.market_id.to_le_bytes().as_ref(),
+                    &[market.bump],
+                ]],
+            ),
+            bond,
+        )?;
+    }
+
+    market.challenger = None;
+    market.challenge_bond = 0;
+    let now = Clock::get()?.unix_timestamp;
+    market.dispute_ends_at = now;
+
+    // A confirmed proposal still has to be settled to return the proposer's bond
+    let status = if market.proposer.is_some() {
+        MarketStatus::Proposed
+    } else {
+        MarketStatus::Finalized
+    };
+    let market_key = market.key();
+    market.set_status(market_key, status, now)?;
+
+    emit!(DisputeResolved {
+        market: market.key(),
+        challenger,
+        overturned,
+        payout_numerators: book.numerators().to_vec(),
+        payout_denominator: market.payout_denominator,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    pub arbiter: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = market.collateral_mint,
+        constraint = Some(challenger_token_account.owner) == market.challenger
+            @ crate::errors::BelievError::UserNotAuthorized
+    )]
+    pub challenger_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = market.collateral_mint,
+        token::authority = global_state.platform_wallet
+    )]
+    pub platform_token_account: Account<'info, TokenAccount>,
+
+    /// Market vault holding the collateral
+    #[account(
+        mut,
+        address = market.vault @ crate::errors::BelievError::InvalidVault,
+        token::authority = market
+    )]
+    pub market_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        address = market.outcome_book
+    )]
+    pub outcome_book: AccountLoader<'info, OutcomeBook>,
+
+    /// Market PDA used as authority for CPI
+    #[account(
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market_vault_authority: AccountInfo<'info>,
+
+    #[account(
+        seeds = [crate::constants::GLOBAL_STATE_SEED],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+use crate::oracle::ed25519;
+use crate::state::{DataPublisher, GlobalState, Market, MarketStatus, OutcomeBook};
+
+/// Event emitted when a market is resolved from its publisher's attestation
+#[event]
+pub struct MarketResolvedFromAttestation {
+    pub market: Pubkey,
+    pub publisher: Pubkey,
+    pub event_id: String,
+    pub winning_outcome: u8,
+    pub attested_at: i64,
+}
+
+/// Resolve a publisher market from the publisher's signed result
+/// Permissionless. The transaction must include, right before this instruction,
+/// an ed25519 program instruction verifying the publisher's signature over
+/// `DataPublisher::attestation_message(market, winning_outcome, timestamp)`.
+/// The timestamp must fall between `resolve_at` and now. The attested outcome
+/// wins and the dispute window opens, lasting at least
+/// `MIN_ATTESTATION_DISPUTE_PERIOD_SECS`. Within the window, an attestation
+/// with a later timestamp replaces the result and reopens the window, so the
+/// latest signed attestation wins (see `Market::check_attestation`).
+pub fn resolve_from_attestation(
+    ctx: Context<ResolveFromAttestation>,
+    winning_outcome: u8,
+    timestamp: i64,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.global_state.is_paused(),
+        crate::errors::BelievError::ProgramPaused
+    );
+
+    let market = &mut ctx.accounts.market;
+    let mut book = ctx.accounts.outcome_book.load_mut()?;
+    let now = Clock::get()?.unix_timestamp;
+
+    let publisher = market.publisher
+        .clone()
+        .ok_or(crate::errors::BelievError::NotPublisherMarket)?;
+
+    // Check resolution time has passed
+    require!(
+        now >= market.resolve_at,
+        crate::errors::BelievError::ResolutionTimeNotReached
+    );
+    market.check_attestation(timestamp, now)?;
+    require!(
+        (winning_outcome as usize) < market.num_outcomes as usize,
+        crate::errors::BelievError::InvalidOutcomeIndex
+    );
+
+    let message = DataPublisher::attestation_message(&market.key(), winning_outcome, timestamp);
+    ed25519::verify_signed_message(
+        &ctx.accounts.instructions_sysvar,
+        &publisher.publisher,
+        &message,
+    )?;
+
+    let mut payout_numerators = vec![0; market.num_outcomes as usize];
+    payout_numerators[winning_outcome as usize] = 1;
+    let market_key = market.key();
+    if market.status.is_unresolved() {
+        market.set_status(market_key, MarketStatus::Resolved, now)?;
+    }
+    market.resolve(&mut book, &payout_numerators, 1)?;
+    market.attested_at = timestamp;
+    let dispute_period_secs = ctx.accounts.global_state.dispute_period_secs
+        .max(crate::constants::MIN_ATTESTATION_DISPUTE_PERIOD_SECS);
+    market.start_dispute_window(now, dispute_period_secs)?;
+
+    emit!(MarketResolvedFromAttestation {
+        market: market.key(),
+        publisher: publisher.publisher,
+        event_id: publisher.event_id,
+        winning_outcome,
+        attested_at: timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ResolveFromAttestation<'info> {
+    pub resolver: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        address = market.outcome_book
+    )]
+    pub outcome_book: AccountLoader<'info, OutcomeBook>,
+
+    /// CHECK: Instructions sysvar, read to find the ed25519 verification instruction
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [crate::constants::GLOBAL_STATE_SEED],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+}
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use crate::oracle::pyth;
+use crate::state::{GlobalState, Market, MarketStatus, OutcomeBook};
+
+/// Event emitted when a price-band market is resolved from its feed
+#[event]
+pub struct MarketResolvedFromPrice {
+    pub market: Pubkey,
+    pub price_feed: Pubkey,
+    pub price: i64,
+    pub expo: i32,
+    pub publish_time: i64,
+    pub winning_outcome: u8,
+}
+
+/// Resolve a price-band market from its Pyth feed
+/// Permissionless. Uses the feed's aggregate price, which must be published
+/// within `MAX_PRICE_STALENESS_SECS` after `resolve_at`, no older than that at
+/// the resolving slot and with a confidence interval within `MAX_PRICE_CONF_BPS`
+/// of the price. The outcome whose band contains the price wins, and the result
+/// is final immediately. If no such price is submitted in time, the market is
+/// left to void at its resolution deadline.
+pub fn resolve_from_price_feed(ctx: Context<ResolveFromPriceFeed>) -> Result<()> {
+    require!(
+        !ctx.accounts.global_state.is_paused(),
+        crate::errors::BelievError::ProgramPaused
+    );
+
+    let market = &mut ctx.accounts.market;
+    let mut book = ctx.accounts.outcome_book.load_mut()?;
+    let now = Clock::get()?.unix_timestamp;
+
+    let bands = market.price_bands
+        .clone()
+        .ok_or(crate::errors::BelievError::NotPriceMarket)?;
+    require!(
+        ctx.accounts.price_feed.key() == bands.feed,
+        crate::errors::BelievError::InvalidPriceFeed
+    );
+
+    // Check resolution time has passed
+    require!(
+        now >= market.resolve_at,
+        crate::errors::BelievError::ResolutionTimeNotReached
+    );
+
+    let reading = pyth::read_price(&ctx.accounts.price_feed)?;
+    reading.validate(market.resolve_at, now)?;
+
+    let price = pyth::rescale(reading.price, reading.expo, bands.expo)
+        .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+    let winning_outcome = bands.outcome_for(price);
+
+    let mut payout_numerators = vec![0; market.num_outcomes as usize];
+    payout_numerators[winning_outcome] = 1;
+    let market_key = market.key();
+    market.set_status(market_key, MarketStatus::Finalized, now)?;
+    market.resolve(&mut book, &payout_numerators, 1)?;
+    market.start_dispute_window(now, 0)?;
+
+    emit!(MarketResolvedFromPrice {
+        market: market.key(),
+        price_feed: bands.feed,
+        price: reading.price,
+        expo: reading.expo,
+        publish_time: reading.publish_time,
+        winning_outcome: winning_outcome as u8,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ResolveFromPriceFeed<'info> {
+    pub resolver: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        address = market.outcome_book
+    )]
+    pub outcome_book: AccountLoader<'info, OutcomeBook>,
+
+    /// CHECK: Pyth price account; validated against the market's bands and decoded by `oracle::pyth`
+    pub price_feed: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [crate::constants::GLOBAL_STATE_SEED],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+}
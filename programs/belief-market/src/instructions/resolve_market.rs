@@ -1,34 +1,35 @@
 use anchor_lang::prelude::*;
-use crate::state::{GlobalState, Market};
+use crate::state::{GlobalState, Market, MarketStatus, OutcomeBook};
 
 /// Event emitted when a market is resolved
 #[event]
 pub struct MarketResolved {
     pub market: Pubkey,
-    pub winning_outcome: u8,
+    pub payout_numerators: Vec<u32>,
+    pub payout_denominator: u32,
     pub resolved_at: i64,
+    pub dispute_ends_at: i64,
 }
 
-/// Resolve a market by setting the winning outcome
-/// Only authority can call this
+/// Resolve a market with a payout vector
+/// Outcome `i` pays `payout_numerators[i] / payout_denominator`, so a single
+/// winner is `[0, 1, 0]` over 1 and a 70/30 split is `[70, 30]` over 100.
+/// Only the market's resolver can call this. Payouts open once the dispute window
+/// closes unchallenged.
 pub fn resolve_market(
     ctx: Context<ResolveMarket>,
-    winning_outcome: u8,
+    payout_numerators: Vec<u32>,
+    payout_denominator: u32,
 ) -> Result<()> {
     let market = &mut ctx.accounts.market;
+    let mut book = ctx.accounts.outcome_book.load_mut()?;
 
-    // Validate authority
+    // Validate resolver
     require!(
-        ctx.accounts.authority.key() == ctx.accounts.global_state.authority,
+        ctx.accounts.resolver.key() == market.resolver,
         crate::errors::BelievError::Unauthorized
     );
 
-    // Validate outcome index
-    require!(
-        (winning_outcome as usize) < market.num_outcomes as usize,
-        crate::errors::BelievError::InvalidOutcomeIndex
-    );
-
     // Check resolution time has passed
     let now = Clock::get()?.unix_timestamp;
     require!(
@@ -36,36 +37,51 @@ pub fn resolve_market(
         crate::errors::BelievError::ResolutionTimeNotReached
     );
 
-    // Ensure not already resolved
+    // Price-band markets can only be resolved from their feed
+    require!(
+        market.price_bands.is_none(),
+        crate::errors::BelievError::ResolvesFromPriceFeed
+    );
+
+    // Publisher markets can only be resolved from an attestation
     require!(
-        !market.resolved,
-        crate::errors::BelievError::MarketAlreadyResolved
+        market.publisher.is_none(),
+        crate::errors::BelievError::ResolvesFromPublisher
     );
 
-    market.resolved = true;
-    market.winning_outcome = Some(winning_outcome);
+    let market_key = market.key();
+    market.set_status(market_key, MarketStatus::Resolved, now)?;
+    market.resolve(&mut book, &payout_numerators, payout_denominator)?;
+    market.start_dispute_window(now, ctx.accounts.global_state.dispute_period_secs)?;
 
     emit!(MarketResolved {
         market: market.key(),
-        winning_outcome,
+        payout_numerators: book.numerators().to_vec(),
+        payout_denominator,
         resolved_at: now,
+        dispute_ends_at: market.dispute_ends_at,
     });
 
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(winning_outcome: u8)]
 pub struct ResolveMarket<'info> {
-    pub authority: Signer<'info>,
+    pub resolver: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [crate::constants::MARKET_SEED, market.creator.as_ref(), market.resolve_at.to_le_bytes().as_ref()],
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
         bump = market.bump
     )]
     pub market: Account<'info, Market>,
 
+    #[account(
+        mut,
+        address = market.outcome_book
+    )]
+    pub outcome_book: AccountLoader<'info, OutcomeBook>,
+
     #[account(
         seeds = [crate::constants::GLOBAL_STATE_SEED],
         bump = global_state.bump
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::{GlobalState, Market};
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+use crate::state::{CostBasis, GlobalState, Market, OutcomeBook, PricingMode};
 
 /// Event emitted when shares are sold
 #[event]
@@ -13,22 +13,33 @@ pub struct SharesSold {
 }
 
 /// Sell shares of a specific outcome
-/// Uses parimutuel pricing model
+/// Priced by the market's pricing mode (parimutuel, LMSR or CPMM)
+/// The sold shares are burned from the seller's outcome token account
+/// Fails if less than `min_amount_out` would be received or `deadline` has passed
 pub fn sell_outcome(
     ctx: Context<SellOutcome>,
     outcome_index: u8,
     shares_to_sell: u64,
+    min_amount_out: u64,
+    deadline: Option<i64>,
 ) -> Result<()> {
     require!(
         !ctx.accounts.global_state.is_paused(),
         crate::errors::BelievError::ProgramPaused
     );
+    if let Some(deadline) = deadline {
+        require!(
+            Clock::get()?.unix_timestamp <= deadline,
+            crate::errors::BelievError::DeadlineExceeded
+        );
+    }
     require!(
         shares_to_sell > 0,
         crate::errors::BelievError::InvalidAmount
     );
 
     let market = &mut ctx.accounts.market;
+    let mut book = ctx.accounts.outcome_book.load_mut()?;
 
     // Validate outcome index
     require!(
@@ -36,42 +47,41 @@ pub fn sell_outcome(
         crate::errors::BelievError::InvalidOutcomeIndex
     );
 
-    // Ensure market not resolved
+    // Ensure market is open for trading
     require!(
-        !market.resolved,
-        crate::errors::BelievError::MarketAlreadyResolved
+        market.is_trading(Clock::get()?.unix_timestamp),
+        crate::errors::BelievError::TradingClosed
     );
 
-    // Ensure seller has enough shares (would need to track in separate account)
-    // For now, we assume this is validated by checking user's derived share account
+    // Ensure seller actually holds the shares being sold
     require!(
-        market.outcome_shares[outcome_index as usize] >= shares_to_sell,
+        ctx.accounts.seller_outcome_account.amount >= shares_to_sell,
+        crate::errors::BelievError::InsufficientShares
+    );
+    require!(
+        book.outcome_shares[outcome_index as usize] >= shares_to_sell,
         crate::errors::BelievError::InsufficientShares
     );
 
-    // Calculate redemption value using parimutuel formula
-    let current_price = market.outcome_pools[outcome_index as usize]
-        .checked_div(market.outcome_shares[outcome_index as usize])
-        .ok_or(crate::errors::BelievError::MarketCalculationError)?;
-
-    let redemption_value = shares_to_sell
-        .checked_mul(current_price)
-        .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+    // Calculate redemption value from the market's pricing mode
+    let redemption_value = market.quote_sell(&book, outcome_index as usize, shares_to_sell)?;
 
     // Calculate fee on redemption
-    let fee_amount = redemption_value
-        .checked_mul(market.trading_fee_bps as u64)
-        .ok_or(crate::errors::BelievError::ArithmeticOverflow)?
-        .checked_div(10000)
-        .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+    let fee_amount = market.trading_fee(redemption_value)?;
 
     let amount_after_fee = redemption_value
         .checked_sub(fee_amount)
         .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
 
+    require!(
+        amount_after_fee >= min_amount_out,
+        crate::errors::BelievError::SlippageExceeded
+    );
+
     // Ensure enough liquidity
     require!(
-        market.outcome_pools[outcome_index as usize] >= amount_after_fee,
+        market.pricing_mode != PricingMode::Parimutuel
+            || book.outcome_pools[outcome_index as usize] >= redemption_value,
         crate::errors::BelievError::InsufficientFunds
     );
 
@@ -87,39 +97,49 @@ pub fn sell_outcome(
             transfer_ix,
             &[&[
                 crate::constants::MARKET_SEED,
-                market.creator.as_ref(),
-                market.resolve_at.to_le_bytes().as_ref(),
+                market.market_id.to_le_bytes().as_ref(),
                 &[market.bump],
             ]],
         ),
         amount_after_fee,
     )?;
 
-    // Update market pools
-    market.outcome_pools[outcome_index as usize] = market.outcome_pools[outcome_index as usize]
-        .checked_sub(amount_after_fee)
-        .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+    // Update market pools: the full redemption value leaves the pools and the
+    // fee portion stays in the vault as fees
+    market.withdraw_from_pools(&mut book, outcome_index as usize, redemption_value)?;
 
-    market.outcome_shares[outcome_index as usize] = market.outcome_shares[outcome_index as usize]
+    book.outcome_shares[outcome_index as usize] = book.outcome_shares[outcome_index as usize]
         .checked_sub(shares_to_sell)
         .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
 
-    // Accumulate fees for creator
-    let creator_fee = fee_amount
-        .checked_mul(crate::constants::FEE_CREATOR_PERCENT as u64)
-        .ok_or(crate::errors::BelievError::ArithmeticOverflow)?
-        .checked_div(100)
-        .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+    market.settle_amm_sell(&mut book, outcome_index as usize, shares_to_sell, redemption_value)?;
 
-    market.accumulated_fees[outcome_index as usize] = market.accumulated_fees[outcome_index as usize]
-        .checked_add(creator_fee)
-        .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+    let cost_basis = &mut ctx.accounts.seller_cost_basis;
+    cost_basis.init_if_new(market.key(), ctx.accounts.seller.key(), ctx.bumps.seller_cost_basis);
+    market.remove_cost_basis(cost_basis, redemption_value);
+
+    // Burn the sold outcome tokens
+    let burn_ix = Burn {
+        mint: ctx.accounts.outcome_mint.to_account_info(),
+        from: ctx.accounts.seller_outcome_account.to_account_info(),
+        authority: ctx.accounts.seller.to_account_info(),
+    };
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            burn_ix,
+        ),
+        shares_to_sell,
+    )?;
+
+    // Accumulate fees for creator
+    market.accrue_fee(&mut book, outcome_index as usize, fee_amount)?;
 
     emit!(SharesSold {
         market: market.key(),
         seller: ctx.accounts.seller.key(),
         outcome_index,
-        shares_sold,
+        shares_sold: shares_to_sell,
         amount_received: amount_after_fee,
     });
 
@@ -127,35 +147,43 @@ pub fn sell_outcome(
 }
 
 #[derive(Accounts)]
-#[instruction(outcome_index: u8, shares_to_sell: u64)]
+#[instruction(outcome_index: u8, shares_to_sell: u64, min_amount_out: u64, deadline: Option<i64>)]
 pub struct SellOutcome<'info> {
     #[account(mut)]
     pub seller: Signer<'info>,
 
     #[account(
         mut,
-        token::mint = crate::constants::USDC_MINT,
+        token::mint = market.collateral_mint,
         token::authority = seller
     )]
     pub seller_token_account: Account<'info, TokenAccount>,
 
+    /// Market vault holding the collateral
     #[account(
         mut,
-        token::mint = crate::constants::USDC_MINT,
+        address = market.vault @ crate::errors::BelievError::InvalidVault,
+        token::authority = market
     )]
     pub market_token_account: Account<'info, TokenAccount>,
 
     /// Market PDA acts as vault authority for token transfers
     #[account(
         mut,
-        seeds = [crate::constants::MARKET_SEED, market.creator.as_ref(), market.resolve_at.to_le_bytes().as_ref()],
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
         bump = market.bump
     )]
     pub market: Account<'info, Market>,
 
+    #[account(
+        mut,
+        address = market.outcome_book
+    )]
+    pub outcome_book: AccountLoader<'info, OutcomeBook>,
+
     /// Market PDA used as authority for CPI
     #[account(
-        seeds = [crate::constants::MARKET_SEED, market.creator.as_ref(), market.resolve_at.to_le_bytes().as_ref()],
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
         bump = market.bump
     )]
     pub market_vault_authority: AccountInfo<'info>,
@@ -166,5 +194,29 @@ pub struct SellOutcome<'info> {
     )]
     pub global_state: Account<'info, GlobalState>,
 
+    #[account(
+        mut,
+        address = market.outcome_mint(&*outcome_book.load()?, outcome_index as usize)?
+    )]
+    pub outcome_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = outcome_mint,
+        token::authority = seller
+    )]
+    pub seller_outcome_account: Account<'info, TokenAccount>,
+
+    /// The seller's net cost in this market, refunded if it is voided
+    #[account(
+        init_if_needed,
+        payer = seller,
+        space = CostBasis::LEN,
+        seeds = [crate::constants::COST_BASIS_SEED, market.key().as_ref(), seller.key().as_ref()],
+        bump
+    )]
+    pub seller_cost_basis: Account<'info, CostBasis>,
+
     pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
 }
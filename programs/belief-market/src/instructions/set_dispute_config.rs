@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::GlobalState;
+
+/// Event emitted when the dispute configuration changes
+#[event]
+pub struct DisputeConfigUpdated {
+    pub dispute_period_secs: i64,
+    pub dispute_bond: u64,
+    pub arbiter: Pubkey,
+}
+
+/// Configure the dispute window, challenge bond and arbiter - only authority can call
+/// Applies to markets resolved after the change
+/// `dispute_bond` is in hundredths of a token and scaled to each market's collateral mint
+pub fn set_dispute_config(
+    ctx: Context<SetDisputeConfig>,
+    dispute_period_secs: i64,
+    dispute_bond: u64,
+    arbiter: Pubkey,
+) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.global_state.authority,
+        crate::errors::BelievError::Unauthorized
+    );
+    require!(
+        (0..=crate::constants::MAX_DISPUTE_PERIOD_SECS).contains(&dispute_period_secs),
+        crate::errors::BelievError::InvalidDisputePeriod
+    );
+
+    let global_state = &mut ctx.accounts.global_state;
+    global_state.dispute_period_secs = dispute_period_secs;
+    global_state.dispute_bond = dispute_bond;
+    global_state.arbiter = arbiter;
+
+    emit!(DisputeConfigUpdated {
+        dispute_period_secs,
+        dispute_bond,
+        arbiter,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetDisputeConfig<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [crate::constants::GLOBAL_STATE_SEED],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+}
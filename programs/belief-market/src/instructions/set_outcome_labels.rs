@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::state::{Market, MarketStatus, OutcomeBook};
+
+/// Event emitted when outcome labels are set
+#[event]
+pub struct OutcomeLabelsSet {
+    pub market: Pubkey,
+    pub start_index: u8,
+    pub labels: Vec<String>,
+}
+
+/// Set the labels of consecutive outcomes starting at `start_index` - only the creator, while in draft
+/// Markets with more outcomes than fit in one transaction are labelled in batches.
+pub fn set_outcome_labels(
+    ctx: Context<SetOutcomeLabels>,
+    start_index: u8,
+    labels: Vec<String>,
+) -> Result<()> {
+    let market = &ctx.accounts.market;
+
+    require!(
+        market.status == MarketStatus::Draft,
+        crate::errors::BelievError::InvalidMarketState
+    );
+    require!(
+        start_index as usize + labels.len() <= market.num_outcomes as usize,
+        crate::errors::BelievError::InvalidOutcomeIndex
+    );
+
+    let mut book = ctx.accounts.outcome_book.load_mut()?;
+    for (offset, label) in labels.iter().enumerate() {
+        book.set_label(start_index as usize + offset, label)?;
+    }
+
+    emit!(OutcomeLabelsSet {
+        market: market.key(),
+        start_index,
+        labels,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetOutcomeLabels<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        has_one = creator @ crate::errors::BelievError::UserNotAuthorized,
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        address = market.outcome_book
+    )]
+    pub outcome_book: AccountLoader<'info, OutcomeBook>,
+}
@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::math::fixed_point::{self, Rounding};
+use crate::state::{GlobalState, Market, MarketStatus, OutcomeBook};
+
+/// Event emitted when a proposed outcome is settled
+#[event]
+pub struct ProposalSettled {
+    pub market: Pubkey,
+    pub proposer: Pubkey,
+    pub bond_returned: u64,
+    pub reward: u64,
+}
+
+/// Settle a proposed outcome once its liveness period has passed unchallenged
+/// (or the arbiter has confirmed it). Permissionless, for markets resolved by the
+/// global authority only. Returns the proposer's bond
+/// plus a reward taken from the market's accumulated fees, and opens payouts.
+pub fn settle_proposal(ctx: Context<SettleProposal>) -> Result<()> {
+    require!(
+        !ctx.accounts.global_state.is_paused(),
+        crate::errors::BelievError::ProgramPaused
+    );
+
+    let market = &mut ctx.accounts.market;
+    let mut book = ctx.accounts.outcome_book.load_mut()?;
+
+    let proposer = market.proposer
+        .ok_or(crate::errors::BelievError::NoPendingProposal)?;
+    require!(
+        market.status == MarketStatus::Proposed,
+        crate::errors::BelievError::ResolutionNotFinal
+    );
+    require!(
+        market.resolver == ctx.accounts.global_state.authority,
+        crate::errors::BelievError::ResolvesFromResolver
+    );
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now >= market.dispute_ends_at,
+        crate::errors::BelievError::ResolutionNotFinal
+    );
+
+    let total_fees = book.total_fees();
+    let reward = fixed_point::mul_div_u64(
+        total_fees,
+        crate::constants::PROPOSER_REWARD_BPS,
+        10000,
+        Rounding::Down,
+    )
+    .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+    market.take_fees(&mut book, reward)?;
+
+    let bond_returned = market.proposal_bond;
+    let payout = bond_returned
+        .checked_add(reward)
+        .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+
+    if payout > 0 {
+        let transfer_ix = Transfer {
+            from: ctx.accounts.market_token_account.to_account_info(),
+            to: ctx.accounts.proposer_token_account.to_account_info(),
+            authority: ctx.accounts.market_vault_authority.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                transfer_ix,
+                &[&[
+                    crate::constants::MARKET_SEED,
+                    market.market_id.to_le_bytes().as_ref(),
+                    &[market.bump],
+                ]],
+            ),
+            payout,
+        )?;
+    }
+
+    market.proposer = None;
+    market.proposal_bond = 0;
+    let market_key = market.key();
+    market.set_status(market_key, MarketStatus::Finalized, now)?;
+
+    emit!(ProposalSettled {
+        market: market.key(),
+        proposer,
+        bond_returned,
+        reward,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SettleProposal<'info> {
+    pub settler: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = market.collateral_mint,
+        constraint = Some(proposer_token_account.owner) == market.proposer
+            @ crate::errors::BelievError::UserNotAuthorized
+    )]
+    pub proposer_token_account: Account<'info, TokenAccount>,
+
+    /// Market vault holding the collateral
+    #[account(
+        mut,
+        address = market.vault @ crate::errors::BelievError::InvalidVault,
+        token::authority = market
+    )]
+    pub market_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        address = market.outcome_book
+    )]
+    pub outcome_book: AccountLoader<'info, OutcomeBook>,
+
+    /// Market PDA used as authority for CPI
+    #[account(
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market_vault_authority: AccountInfo<'info>,
+
+    #[account(
+        seeds = [crate::constants::GLOBAL_STATE_SEED],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    pub token_program: Program<'info, Token>,
+}
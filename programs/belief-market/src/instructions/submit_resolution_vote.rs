@@ -0,0 +1,174 @@
+use anchor_lang::prelude::*;
+use crate::state::{CouncilVote, GlobalState, Market, MarketStatus, OutcomeBook, ResolutionVotes, ResolverCouncil};
+
+/// Event emitted for every council vote
+#[event]
+pub struct ResolutionVoteSubmitted {
+    pub market: Pubkey,
+    pub council: Pubkey,
+    pub member: Pubkey,
+    pub payout_numerators: Vec<u32>,
+    pub payout_denominator: u32,
+    pub matching_votes: u8,
+}
+
+/// Event emitted when a vote disagrees with one already cast
+#[event]
+pub struct ResolutionVoteMismatch {
+    pub market: Pubkey,
+    pub council: Pubkey,
+    pub member: Pubkey,
+    pub conflicting_member: Pubkey,
+}
+
+/// Vote on the payout vector of a market whose resolver is a council
+/// Only council members can call this. A member voting again replaces their
+/// earlier vote. Once `threshold` votes agree, the market resolves with that
+/// payout vector and its dispute window opens.
+pub fn submit_resolution_vote(
+    ctx: Context<SubmitResolutionVote>,
+    payout_numerators: Vec<u32>,
+    payout_denominator: u32,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.global_state.is_paused(),
+        crate::errors::BelievError::ProgramPaused
+    );
+
+    let council = &ctx.accounts.council;
+    let market = &mut ctx.accounts.market;
+    let mut book = ctx.accounts.outcome_book.load_mut()?;
+    let votes = &mut ctx.accounts.votes;
+    let member = ctx.accounts.member.key();
+
+    require!(
+        council.is_member(&member),
+        crate::errors::BelievError::NotCouncilMember
+    );
+    require!(
+        market.status.is_unresolved(),
+        crate::errors::BelievError::MarketAlreadyResolved
+    );
+    require!(
+        market.price_bands.is_none(),
+        crate::errors::BelievError::ResolvesFromPriceFeed
+    );
+    require!(
+        market.publisher.is_none(),
+        crate::errors::BelievError::ResolvesFromPublisher
+    );
+
+    // Check resolution time has passed
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now >= market.resolve_at,
+        crate::errors::BelievError::ResolutionTimeNotReached
+    );
+
+    if votes.market == Pubkey::default() {
+        votes.market = market.key();
+        votes.council = council.key();
+        votes.bump = ctx.bumps.votes;
+    }
+
+    // Votes are held to the same rules as a resolution, so a malformed vote
+    // cannot agree with others
+    market.validate_payout_vector(&payout_numerators, payout_denominator)?;
+
+    let vote = CouncilVote {
+        member,
+        payout_numerators,
+        payout_denominator,
+    };
+
+    // Record disagreements with other members before replacing this member's vote
+    let conflicting: Vec<Pubkey> = votes.votes
+        .iter()
+        .filter(|cast| cast.member != member && !cast.agrees_with(&vote))
+        .map(|cast| cast.member)
+        .collect();
+    if !conflicting.is_empty() {
+        votes.mismatches = votes.mismatches.saturating_add(1);
+    }
+    for conflicting_member in conflicting {
+        emit!(ResolutionVoteMismatch {
+            market: market.key(),
+            council: council.key(),
+            member,
+            conflicting_member,
+        });
+    }
+
+    votes.votes.retain(|cast| cast.member != member);
+    votes.votes.push(vote.clone());
+    let matching_votes = votes.matching(&vote);
+
+    emit!(ResolutionVoteSubmitted {
+        market: market.key(),
+        council: council.key(),
+        member,
+        payout_numerators: vote.payout_numerators.clone(),
+        payout_denominator: vote.payout_denominator,
+        matching_votes: matching_votes as u8,
+    });
+
+    if matching_votes >= council.threshold as usize {
+        let market_key = market.key();
+        market.set_status(market_key, MarketStatus::Resolved, now)?;
+        market.resolve(&mut book, &vote.payout_numerators, vote.payout_denominator)?;
+        market.start_dispute_window(now, ctx.accounts.global_state.dispute_period_secs)?;
+
+        emit!(crate::instructions::MarketResolved {
+            market: market.key(),
+            payout_numerators: vote.payout_numerators,
+            payout_denominator: vote.payout_denominator,
+            resolved_at: now,
+            dispute_ends_at: market.dispute_ends_at,
+        });
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SubmitResolutionVote<'info> {
+    #[account(mut)]
+    pub member: Signer<'info>,
+
+    #[account(
+        seeds = [crate::constants::COUNCIL_SEED, council.authority.as_ref(), council.council_id.to_le_bytes().as_ref()],
+        bump = council.bump
+    )]
+    pub council: Account<'info, ResolverCouncil>,
+
+    #[account(
+        mut,
+        constraint = market.resolver == council.key() @ crate::errors::BelievError::CouncilNotResolver,
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        address = market.outcome_book
+    )]
+    pub outcome_book: AccountLoader<'info, OutcomeBook>,
+
+    #[account(
+        init_if_needed,
+        payer = member,
+        space = ResolutionVotes::LEN,
+        seeds = [crate::constants::RESOLUTION_VOTES_SEED, market.key().as_ref()],
+        bump
+    )]
+    pub votes: Account<'info, ResolutionVotes>,
+
+    #[account(
+        seeds = [crate::constants::GLOBAL_STATE_SEED],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    pub system_program: Program<'info, System>,
+}
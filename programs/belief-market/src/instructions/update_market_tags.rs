@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::Market;
+
+/// Event emitted when a market's tags are replaced
+#[event]
+pub struct MarketTagsUpdated {
+    pub market: Pubkey,
+    pub tags: Vec<String>,
+}
+
+/// Replace a market's tags - only the creator, before resolution
+/// The market account is resized to fit; the creator pays for any extra rent
+/// and is refunded when it shrinks.
+pub fn update_market_tags(ctx: Context<UpdateMarketTags>, tags: Vec<String>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+
+    require!(
+        market.status.is_unresolved(),
+        crate::errors::BelievError::MarketAlreadyResolved
+    );
+    require!(
+        tags.len() <= Market::MAX_TAGS,
+        crate::errors::BelievError::StringTooLong
+    );
+
+    for tag in &tags {
+        require!(
+            tag.len() <= Market::MAX_TAG_LEN,
+            crate::errors::BelievError::StringTooLong
+        );
+    }
+
+    market.tags = tags;
+
+    emit!(MarketTagsUpdated {
+        market: market.key(),
+        tags: market.tags.clone(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(tags: Vec<String>)]
+pub struct UpdateMarketTags<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = creator @ crate::errors::BelievError::UserNotAuthorized,
+        realloc = Market::space(&tags, &market.price_bands, &market.publisher),
+        realloc::payer = creator,
+        realloc::zero = false,
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
-use crate::state::Market;
+use crate::math::fixed_point::{self, Rounding};
+use crate::state::{Market, OutcomeBook, PricingMode, ReferrerCode};
+use super::outcome_tokens;
 
 /// View function to get current odds for an outcome
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -12,13 +14,14 @@ pub struct OutcomeOdds {
 /// Get current market odds for all outcomes
 pub fn get_market_odds(ctx: Context<GetMarketOdds>) -> Result<Vec<OutcomeOdds>> {
     let market = &ctx.accounts.market;
+    let book = ctx.accounts.outcome_book.load()?;
 
     let mut odds_vec = Vec::new();
     for i in 0..(market.num_outcomes as usize) {
         odds_vec.push(OutcomeOdds {
             outcome_index: i as u8,
-            odds_bps: market.get_outcome_odds(i),
-            outcome_label: market.outcome_labels[i].clone(),
+            odds_bps: market.get_outcome_odds(&book, i),
+            outcome_label: book.label(i),
         });
     }
 
@@ -28,34 +31,129 @@ pub fn get_market_odds(ctx: Context<GetMarketOdds>) -> Result<Vec<OutcomeOdds>>
 #[derive(Accounts)]
 pub struct GetMarketOdds<'info> {
     #[account(
-        seeds = [crate::constants::MARKET_SEED, market.creator.as_ref(), market.resolve_at.to_le_bytes().as_ref()],
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
         bump = market.bump
     )]
     pub market: Account<'info, Market>,
+
+    #[account(
+        address = market.outcome_book
+    )]
+    pub outcome_book: AccountLoader<'info, OutcomeBook>,
 }
 
 /// View function to get user position in a market outcome
 #[derive(AnchorSerialize, AnchorDeserialize)]
-pub struct UserPosition {
+pub struct OutcomePosition {
     pub outcome_index: u8,
     pub shares_owned: u64,
     pub current_value: u64,
 }
 
-/// Get user's current position in market
+/// Get user's current position in every outcome of the market
+/// Remaining accounts: the user's token account for every outcome, in order.
+/// Accounts that have not been created yet count as holding no shares.
 pub fn get_user_position(
-    _ctx: Context<GetUserPosition>,
-) -> Result<UserPosition> {
-    // Note: In a real implementation, we would track user shares in separate PDAs
-    // For now, this is a placeholder showing the structure
-    Ok(UserPosition {
-        outcome_index: 0,
-        shares_owned: 0,
-        current_value: 0,
-    })
+    ctx: Context<GetUserPosition>,
+) -> Result<Vec<OutcomePosition>> {
+    let market = &ctx.accounts.market;
+    let book = ctx.accounts.outcome_book.load()?;
+
+    require!(
+        ctx.remaining_accounts.len() == market.num_outcomes as usize,
+        crate::errors::BelievError::InvalidOutcomeTokenAccount
+    );
+
+    let mut positions = Vec::new();
+    for i in 0..(market.num_outcomes as usize) {
+        let account_info = &ctx.remaining_accounts[i];
+        let shares_owned = if account_info.data_is_empty() {
+            0
+        } else {
+            outcome_tokens::load_outcome_token_account(
+                account_info,
+                &market.outcome_mint(&book, i)?,
+                &ctx.accounts.user.key(),
+            )?
+            .amount
+        };
+
+        // Value shares at the outcome's current price
+        let current_value = match market.pricing_mode {
+            PricingMode::Parimutuel if book.outcome_shares[i] == 0 => 0,
+            PricingMode::Parimutuel => fixed_point::mul_div_u64(
+                shares_owned,
+                book.outcome_pools[i],
+                book.outcome_shares[i],
+                Rounding::Down,
+            )
+            .ok_or(crate::errors::BelievError::MarketCalculationError)?,
+            PricingMode::Lmsr | PricingMode::Cpmm => fixed_point::mul_div_u64(
+                shares_owned,
+                market.get_outcome_odds(&book, i),
+                10000,
+                Rounding::Down,
+            )
+            .ok_or(crate::errors::BelievError::MarketCalculationError)?,
+        };
+
+        positions.push(OutcomePosition {
+            outcome_index: i as u8,
+            shares_owned,
+            current_value,
+        });
+    }
+
+    Ok(positions)
 }
 
 #[derive(Accounts)]
 pub struct GetUserPosition<'info> {
+    #[account(
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
     pub market: Account<'info, Market>,
+
+    #[account(
+        address = market.outcome_book
+    )]
+    pub outcome_book: AccountLoader<'info, OutcomeBook>,
+
+    /// CHECK: Owner of the outcome token accounts being read
+    pub user: UncheckedAccount<'info>,
+}
+
+/// View function to resolve a referrer code to its owner
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ReferrerCodeOwner {
+    pub referrer_code: String,
+    pub owner: Pubkey,
+    pub profile: Pubkey,
+}
+
+/// Look up who owns a referrer code, e.g. to resolve `?ref=CODE` links
+/// The code is normalized first, so lookups are case-insensitive.
+/// Fails if the code is not registered.
+pub fn get_referrer_code_owner(
+    ctx: Context<GetReferrerCodeOwner>,
+    _referrer_code: String,
+) -> Result<ReferrerCodeOwner> {
+    let entry = &ctx.accounts.referrer_code_account;
+
+    Ok(ReferrerCodeOwner {
+        referrer_code: entry.code.clone(),
+        owner: entry.owner,
+        profile: entry.profile,
+    })
+}
+
+#[derive(Accounts)]
+#[instruction(referrer_code: String)]
+pub struct GetReferrerCodeOwner<'info> {
+    #[account(
+        seeds = [crate::constants::REFERRER_CODE_SEED, ReferrerCode::normalize(&referrer_code)?.as_bytes()],
+        bump = referrer_code_account.bump
+    )]
+    pub referrer_code_account: Account<'info, ReferrerCode>,
 }
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{Market, MarketStatus, OutcomeBook, VoidReason};
+
+/// Void a market its resolver never resolved - permissionless
+/// Callable by anyone once `resolution_deadline` has passed, including on markets
+/// whose proposal or dispute is still pending. Any pending outcome is discarded and
+/// the proposal and challenge bonds go back to their posters, whose token accounts
+/// must then be passed. Holders then reclaim their net cost with `claim_refund`,
+/// and the creator keeps their peg since the missing resolution is not their fault.
+pub fn void_expired_market(ctx: Context<VoidExpiredMarket>) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let mut book = ctx.accounts.outcome_book.load_mut()?;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Ensure no resolution has become final
+    require!(
+        market.status.is_unresolved()
+            || matches!(market.status, MarketStatus::Proposed | MarketStatus::Disputed),
+        crate::errors::BelievError::MarketAlreadyResolved
+    );
+    require!(
+        now >= market.resolution_deadline,
+        crate::errors::BelievError::ResolutionDeadlineNotReached
+    );
+
+    // Return the bonds of a pending proposal and challenge
+    let bonds = [
+        (market.proposal_bond, &ctx.accounts.proposer_token_account),
+        (market.challenge_bond, &ctx.accounts.challenger_token_account),
+    ];
+    for (bond, destination) in bonds {
+        if bond == 0 {
+            continue;
+        }
+        let destination = destination
+            .as_ref()
+            .ok_or(crate::errors::BelievError::UserNotAuthorized)?;
+        let transfer_ix = Transfer {
+            from: ctx.accounts.market_token_account.to_account_info(),
+            to: destination.to_account_info(),
+            authority: ctx.accounts.market_vault_authority.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                transfer_ix,
+                &[&[
+                    crate::constants::MARKET_SEED,
+                    market.market_id.to_le_bytes().as_ref(),
+                    &[market.bump],
+                ]],
+            ),
+            bond,
+        )?;
+    }
+    market.proposer = None;
+    market.proposal_bond = 0;
+    market.challenger = None;
+    market.challenge_bond = 0;
+
+    let market_key = market.key();
+    market.set_status(market_key, MarketStatus::Voided, now)?;
+    market.clear_resolution(&mut book);
+    market.void_reason = Some(VoidReason::ResolutionExpired);
+    // Voiding proposes no outcome, so there is nothing to dispute
+    market.dispute_ends_at = now;
+
+    emit!(crate::instructions::MarketVoided {
+        market: market.key(),
+        reason: VoidReason::ResolutionExpired,
+        peg_forfeited: 0,
+        voided_at: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct VoidExpiredMarket<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    #[account(
+        mut,
+        address = market.outcome_book
+    )]
+    pub outcome_book: AccountLoader<'info, OutcomeBook>,
+
+    /// Proposer's collateral account; required while a proposal bond is posted
+    #[account(
+        mut,
+        token::mint = market.collateral_mint,
+        constraint = Some(proposer_token_account.owner) == market.proposer
+            @ crate::errors::BelievError::UserNotAuthorized
+    )]
+    pub proposer_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Challenger's collateral account; required while a challenge bond is posted
+    #[account(
+        mut,
+        token::mint = market.collateral_mint,
+        constraint = Some(challenger_token_account.owner) == market.challenger
+            @ crate::errors::BelievError::UserNotAuthorized
+    )]
+    pub challenger_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Market vault holding the collateral
+    #[account(
+        mut,
+        address = market.vault @ crate::errors::BelievError::InvalidVault,
+        token::authority = market
+    )]
+    pub market_token_account: Account<'info, TokenAccount>,
+
+    /// Market PDA used as authority for CPI
+    #[account(
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market_vault_authority: AccountInfo<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
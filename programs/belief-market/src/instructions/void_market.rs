@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{GlobalState, Market, MarketStatus, VoidReason};
+
+/// Event emitted when a market is voided
+#[event]
+pub struct MarketVoided {
+    pub market: Pubkey,
+    pub reason: VoidReason,
+    pub peg_forfeited: u64,
+    pub voided_at: i64,
+}
+
+/// Void a market instead of resolving it to a winner
+/// Only authority can call this, at any time before resolution.
+/// Holders then reclaim their net cost with `claim_refund`.
+/// The creator peg goes to the platform unless the event was cancelled or the
+/// resolution expired; the rest of the creation fee was paid out at creation and
+/// is kept for every reason.
+pub fn void_market(ctx: Context<VoidMarket>, reason: VoidReason) -> Result<()> {
+    let market = &mut ctx.accounts.market;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Validate authority
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.global_state.authority,
+        crate::errors::BelievError::Unauthorized
+    );
+
+    // Ensure not already resolved
+    require!(
+        market.status.is_unresolved(),
+        crate::errors::BelievError::MarketAlreadyResolved
+    );
+
+    let market_key = market.key();
+    market.set_status(market_key, MarketStatus::Voided, now)?;
+    market.void_reason = Some(reason);
+    // Voiding proposes no outcome, so there is nothing to dispute
+    market.dispute_ends_at = now;
+
+    let peg_forfeited = if reason.forfeits_creator_peg() {
+        market.creator_peg_amount
+    } else {
+        0
+    };
+
+    if peg_forfeited > 0 {
+        let transfer_ix = Transfer {
+            from: ctx.accounts.market_token_account.to_account_info(),
+            to: ctx.accounts.platform_token_account.to_account_info(),
+            authority: ctx.accounts.market_vault_authority.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                transfer_ix,
+                &[&[
+                    crate::constants::MARKET_SEED,
+                    market.market_id.to_le_bytes().as_ref(),
+                    &[market.bump],
+                ]],
+            ),
+            peg_forfeited,
+        )?;
+
+        market.creator_peg_amount = 0;
+    }
+
+    emit!(MarketVoided {
+        market: market.key(),
+        reason,
+        peg_forfeited,
+        voided_at: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct VoidMarket<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        token::mint = market.collateral_mint,
+        token::authority = global_state.platform_wallet
+    )]
+    pub platform_token_account: Account<'info, TokenAccount>,
+
+    /// Market vault holding the collateral
+    #[account(
+        mut,
+        address = market.vault @ crate::errors::BelievError::InvalidVault,
+        token::authority = market
+    )]
+    pub market_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market: Account<'info, Market>,
+
+    /// Market PDA used as authority for CPI
+    #[account(
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
+        bump = market.bump
+    )]
+    pub market_vault_authority: AccountInfo<'info>,
+
+    #[account(
+        seeds = [crate::constants::GLOBAL_STATE_SEED],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    pub token_program: Program<'info, Token>,
+}
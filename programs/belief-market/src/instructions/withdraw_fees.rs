@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::{GlobalState, Market, UserProfile};
+use crate::state::{GlobalState, Market, OutcomeBook};
 
 /// Event emitted when fees are withdrawn
 #[event]
@@ -11,7 +11,8 @@ pub struct FeesWithdrawn {
 }
 
 /// Withdraw accumulated trading fees
-/// Distributes fees: 80% to creator, 10% to invitor, 10% to platform
+/// Distributes fees: 80% to creator, 10% to invitor, 10% to platform. Without an
+/// invitor, the invitor's share goes to the platform.
 pub fn withdraw_fees(
     ctx: Context<WithdrawFees>,
 ) -> Result<()> {
@@ -21,37 +22,36 @@ pub fn withdraw_fees(
     );
 
     let market = &mut ctx.accounts.market;
+    let mut book = ctx.accounts.outcome_book.load_mut()?;
 
     // Ensure market is resolved
     require!(
-        market.resolved,
+        !market.status.is_unresolved(),
         crate::errors::BelievError::CannotWithdrawUnresolved
     );
 
-    let total_fees: u64 = market.accumulated_fees.iter().sum();
+    // Ensure the resolution can no longer be challenged
+    let market_key = market.key();
+    market.finalize_if_due(market_key, Clock::get()?.unix_timestamp)?;
+    require!(
+        market.status.is_settled(),
+        crate::errors::BelievError::ResolutionNotFinal
+    );
+
+    let total_fees = book.total_fees();
     require!(
         total_fees > 0,
         crate::errors::BelievError::NoFeesToWithdraw
     );
 
     // Calculate fee distribution: 80% creator, 10% invitor, 10% platform
-    let creator_share = total_fees
-        .checked_mul(crate::constants::FEE_CREATOR_PERCENT as u64)
-        .ok_or(crate::errors::BelievError::ArithmeticOverflow)?
-        .checked_div(100)
-        .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
-
-    let invitor_share = total_fees
-        .checked_mul(crate::constants::FEE_INVITOR_PERCENT as u64)
-        .ok_or(crate::errors::BelievError::ArithmeticOverflow)?
-        .checked_div(100)
-        .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
-
-    let platform_share = total_fees
-        .checked_sub(creator_share)
-        .ok_or(crate::errors::BelievError::ArithmeticOverflow)?
-        .checked_sub(invitor_share)
-        .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+    let (creator_share, invitor_share, platform_share) = market.fee_shares(total_fees)?;
+    if let Some(invitor) = market.invitor {
+        require!(
+            ctx.accounts.invitor_token_account.owner == invitor,
+            crate::errors::BelievError::UserNotAuthorized
+        );
+    }
 
     // Transfer creator share
     if creator_share > 0 {
@@ -66,8 +66,7 @@ pub fn withdraw_fees(
                 transfer_ix,
                 &[&[
                     crate::constants::MARKET_SEED,
-                    market.creator.as_ref(),
-                    market.resolve_at.to_le_bytes().as_ref(),
+                    market.market_id.to_le_bytes().as_ref(),
                     &[market.bump],
                 ]],
             ),
@@ -76,27 +75,24 @@ pub fn withdraw_fees(
     }
 
     // Transfer invitor share if invitor exists
-    if let Some(invitor) = market.invitor {
-        if invitor_share > 0 {
-            let transfer_ix = Transfer {
-                from: ctx.accounts.market_token_account.to_account_info(),
-                to: ctx.accounts.invitor_token_account.to_account_info(),
-                authority: ctx.accounts.market_vault_authority.to_account_info(),
-            };
-            token::transfer(
-                CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    transfer_ix,
-                    &[&[
-                        crate::constants::MARKET_SEED,
-                        market.creator.as_ref(),
-                        market.resolve_at.to_le_bytes().as_ref(),
-                        &[market.bump],
-                    ]],
-                ),
-                invitor_share,
-            )?;
-        }
+    if invitor_share > 0 {
+        let transfer_ix = Transfer {
+            from: ctx.accounts.market_token_account.to_account_info(),
+            to: ctx.accounts.invitor_token_account.to_account_info(),
+            authority: ctx.accounts.market_vault_authority.to_account_info(),
+        };
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                transfer_ix,
+                &[&[
+                    crate::constants::MARKET_SEED,
+                    market.market_id.to_le_bytes().as_ref(),
+                    &[market.bump],
+                ]],
+            ),
+            invitor_share,
+        )?;
     }
 
     // Transfer platform share
@@ -112,8 +108,7 @@ pub fn withdraw_fees(
                 transfer_ix,
                 &[&[
                     crate::constants::MARKET_SEED,
-                    market.creator.as_ref(),
-                    market.resolve_at.to_le_bytes().as_ref(),
+                    market.market_id.to_le_bytes().as_ref(),
                     &[market.bump],
                 ]],
             ),
@@ -122,7 +117,7 @@ pub fn withdraw_fees(
     }
 
     // Clear accumulated fees
-    market.accumulated_fees = vec![0; market.num_outcomes as usize];
+    book.accumulated_fees.fill(0);
 
     emit!(FeesWithdrawn {
         market: market.key(),
@@ -139,40 +134,49 @@ pub struct WithdrawFees<'info> {
 
     #[account(
         mut,
-        token::mint = crate::constants::USDC_MINT,
+        token::mint = market.collateral_mint,
         token::authority = market.creator
     )]
     pub creator_token_account: Account<'info, TokenAccount>,
 
+    /// Invitor's collateral account; unused when the market has no invitor
     #[account(
         mut,
-        token::mint = crate::constants::USDC_MINT,
+        token::mint = market.collateral_mint,
     )]
     pub invitor_token_account: Account<'info, TokenAccount>,
 
     #[account(
         mut,
-        token::mint = crate::constants::USDC_MINT,
+        token::mint = market.collateral_mint,
         token::authority = global_state.platform_wallet
     )]
     pub platform_token_account: Account<'info, TokenAccount>,
 
+    /// Market vault holding the collateral
     #[account(
         mut,
-        token::mint = crate::constants::USDC_MINT,
+        address = market.vault @ crate::errors::BelievError::InvalidVault,
+        token::authority = market
     )]
     pub market_token_account: Account<'info, TokenAccount>,
 
     #[account(
         mut,
-        seeds = [crate::constants::MARKET_SEED, market.creator.as_ref(), market.resolve_at.to_le_bytes().as_ref()],
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
         bump = market.bump
     )]
     pub market: Account<'info, Market>,
 
+    #[account(
+        mut,
+        address = market.outcome_book
+    )]
+    pub outcome_book: AccountLoader<'info, OutcomeBook>,
+
     /// Market PDA used as authority for CPI
     #[account(
-        seeds = [crate::constants::MARKET_SEED, market.creator.as_ref(), market.resolve_at.to_le_bytes().as_ref()],
+        seeds = [crate::constants::MARKET_SEED, market.market_id.to_le_bytes().as_ref()],
         bump = market.bump
     )]
     pub market_vault_authority: AccountInfo<'info>,
@@ -5,9 +5,12 @@ declare_id!("11111111111111111111111111111111");
 pub mod constants;
 pub mod errors;
 pub mod instructions;
+pub mod math;
+pub mod oracle;
 pub mod state;
 
 use instructions::*;
+use state::{MarketStatus, OrderSide, PricingMode, VoidReason};
 
 #[program]
 pub mod belief_market {
@@ -21,7 +24,7 @@ pub mod belief_market {
         instructions::initialize_global(ctx, platform_wallet)
     }
 
-    /// Initialize user profile with referrer code
+    /// Initialize user profile and register its unique referrer code
     pub fn initialize_user(
         ctx: Context<InitializeUser>,
         referrer_code: String,
@@ -29,23 +32,40 @@ pub mod belief_market {
         instructions::initialize_user(ctx, referrer_code)
     }
 
+    /// Register the referrer code of a profile created before the code registry - only authority
+    pub fn backfill_referrer_code(ctx: Context<BackfillReferrerCode>) -> Result<()> {
+        instructions::backfill_referrer_code(ctx)
+    }
+
     /// Create a new prediction market
-    pub fn create_market(
-        ctx: Context<CreateMarket>,
-        num_outcomes: u8,
-        outcome_labels: Vec<String>,
-        tags: Vec<String>,
-        trading_fee_bps: u16,
-        resolve_at: i64,
-    ) -> Result<()> {
-        instructions::create_market(
-            ctx,
-            num_outcomes,
-            outcome_labels,
-            tags,
-            trading_fee_bps,
-            resolve_at,
-        )
+    pub fn create_market(ctx: Context<CreateMarket>, params: CreateMarketParams) -> Result<()> {
+        instructions::create_market(ctx, params)
+    }
+
+    /// Replace a market's tags - only creator
+    pub fn update_market_tags(ctx: Context<UpdateMarketTags>, tags: Vec<String>) -> Result<()> {
+        instructions::update_market_tags(ctx, tags)
+    }
+
+    /// Set outcome labels in batches while the market is in draft - only creator
+    pub fn set_outcome_labels(
+        ctx: Context<SetOutcomeLabels>,
+        start_index: u8,
+        labels: Vec<String>,
+    ) -> Result<()> {
+        instructions::set_outcome_labels(ctx, start_index, labels)
+    }
+
+    /// Grow the legacy global state to the current layout - only authority
+    pub fn migrate_global_state(ctx: Context<MigrateGlobalState>) -> Result<()> {
+        instructions::migrate_global_state(ctx)
+    }
+
+    /// Move a legacy market to its ID-seeded address and current layout - only authority
+    pub fn migrate_market<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateMarket<'info>>,
+    ) -> Result<()> {
+        instructions::migrate_market(ctx)
     }
 
     /// Buy shares of an outcome
@@ -53,8 +73,10 @@ pub mod belief_market {
         ctx: Context<BuyOutcome>,
         outcome_index: u8,
         amount_usdc: u64,
+        min_shares_out: u64,
+        deadline: Option<i64>,
     ) -> Result<()> {
-        instructions::buy_outcome(ctx, outcome_index, amount_usdc)
+        instructions::buy_outcome(ctx, outcome_index, amount_usdc, min_shares_out, deadline)
     }
 
     /// Sell shares of an outcome
@@ -62,24 +84,110 @@ pub mod belief_market {
         ctx: Context<SellOutcome>,
         outcome_index: u8,
         shares_to_sell: u64,
+        min_amount_out: u64,
+        deadline: Option<i64>,
     ) -> Result<()> {
-        instructions::sell_outcome(ctx, outcome_index, shares_to_sell)
+        instructions::sell_outcome(ctx, outcome_index, shares_to_sell, min_amount_out, deadline)
     }
 
-    /// Resolve a market with the winning outcome
+    /// Resolve a market with a payout vector - only the market's resolver
     pub fn resolve_market(
         ctx: Context<ResolveMarket>,
+        payout_numerators: Vec<u32>,
+        payout_denominator: u32,
+    ) -> Result<()> {
+        instructions::resolve_market(ctx, payout_numerators, payout_denominator)
+    }
+
+    /// Halt trading on a market past its resolution time - permissionless
+    pub fn close_trading(ctx: Context<CloseTrading>) -> Result<()> {
+        instructions::close_trading(ctx)
+    }
+
+    /// Finalize a resolved market after its dispute window - permissionless
+    pub fn finalize_market(ctx: Context<FinalizeMarket>) -> Result<()> {
+        instructions::finalize_market(ctx)
+    }
+
+    /// Create an M-of-N resolver council
+    pub fn create_resolver_council(
+        ctx: Context<CreateResolverCouncil>,
+        council_id: u64,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::create_resolver_council(ctx, council_id, members, threshold)
+    }
+
+    /// Vote on a market's outcome as a member of its resolver council
+    pub fn submit_resolution_vote(
+        ctx: Context<SubmitResolutionVote>,
+        payout_numerators: Vec<u32>,
+        payout_denominator: u32,
+    ) -> Result<()> {
+        instructions::submit_resolution_vote(ctx, payout_numerators, payout_denominator)
+    }
+
+    /// Resolve a price-band market from its Pyth feed - permissionless
+    pub fn resolve_from_price_feed(ctx: Context<ResolveFromPriceFeed>) -> Result<()> {
+        instructions::resolve_from_price_feed(ctx)
+    }
+
+    /// Resolve a publisher market from a signed attestation - permissionless
+    pub fn resolve_from_attestation(
+        ctx: Context<ResolveFromAttestation>,
         winning_outcome: u8,
+        timestamp: i64,
     ) -> Result<()> {
-        instructions::resolve_market(ctx, winning_outcome)
+        instructions::resolve_from_attestation(ctx, winning_outcome, timestamp)
     }
 
-    /// Redeem winnings from a resolved market
-    pub fn redeem_winnings(
-        ctx: Context<RedeemWinnings>,
-        winning_shares: u64,
+    /// Void a market instead of resolving it - only authority
+    pub fn void_market(ctx: Context<VoidMarket>, reason: VoidReason) -> Result<()> {
+        instructions::void_market(ctx, reason)
+    }
+
+    /// Void a market left unresolved or still disputed past its deadline - permissionless
+    pub fn void_expired_market(ctx: Context<VoidExpiredMarket>) -> Result<()> {
+        instructions::void_expired_market(ctx)
+    }
+
+    /// Claim a refund of the caller's net cost in a voided market - permissionless
+    pub fn claim_refund(ctx: Context<ClaimRefund>) -> Result<()> {
+        instructions::claim_refund(ctx)
+    }
+
+    /// Propose an outcome with a bond once resolution time has passed - only markets resolved by the authority
+    pub fn propose_outcome(
+        ctx: Context<ProposeOutcome>,
+        payout_numerators: Vec<u32>,
+        payout_denominator: u32,
+    ) -> Result<()> {
+        instructions::propose_outcome(ctx, payout_numerators, payout_denominator)
+    }
+
+    /// Settle an unchallenged proposal and reward its proposer
+    pub fn settle_proposal(ctx: Context<SettleProposal>) -> Result<()> {
+        instructions::settle_proposal(ctx)
+    }
+
+    /// Challenge a resolution during its dispute window by posting a bond
+    pub fn challenge_resolution(ctx: Context<ChallengeResolution>) -> Result<()> {
+        instructions::challenge_resolution(ctx)
+    }
+
+    /// Confirm or overturn a challenged resolution - only arbiter
+    pub fn resolve_dispute(
+        ctx: Context<ResolveDispute>,
+        payout_numerators: Option<Vec<u32>>,
+        payout_denominator: u32,
     ) -> Result<()> {
-        instructions::redeem_winnings(ctx, winning_shares)
+        instructions::resolve_dispute(ctx, payout_numerators, payout_denominator)
+    }
+
+    /// Redeem winnings from a resolved market
+    pub fn redeem_winnings(ctx: Context<RedeemWinnings>, outcome_index: u8) -> Result<()> {
+        instructions::redeem_winnings(ctx, outcome_index)
     }
 
     /// Claim creator peg ($1 returned after resolution)
@@ -92,6 +200,11 @@ pub mod belief_market {
         instructions::withdraw_fees(ctx)
     }
 
+    /// Close a fully settled market and reclaim its rent - permissionless
+    pub fn close_market<'info>(ctx: Context<'_, '_, '_, 'info, CloseMarket<'info>>) -> Result<()> {
+        instructions::close_market(ctx)
+    }
+
     /// Emergency pause - only authority
     pub fn emergency_pause(ctx: Context<EmergencyPause>) -> Result<()> {
         instructions::emergency_pause(ctx)
@@ -102,15 +215,88 @@ pub mod belief_market {
         instructions::emergency_unpause(ctx)
     }
 
+    /// Configure the dispute window, bond and arbiter - only authority
+    pub fn set_dispute_config(
+        ctx: Context<SetDisputeConfig>,
+        dispute_period_secs: i64,
+        dispute_bond: u64,
+        arbiter: Pubkey,
+    ) -> Result<()> {
+        instructions::set_dispute_config(ctx, dispute_period_secs, dispute_bond, arbiter)
+    }
+
+    /// Allow a collateral mint for new markets - only authority
+    pub fn add_collateral_mint(ctx: Context<AddCollateralMint>) -> Result<()> {
+        instructions::add_collateral_mint(ctx)
+    }
+
+    /// Remove a collateral mint from the allowlist - only authority
+    pub fn remove_collateral_mint(ctx: Context<RemoveCollateralMint>, mint: Pubkey) -> Result<()> {
+        instructions::remove_collateral_mint(ctx, mint)
+    }
+
+    /// Place a limit order for outcome shares
+    pub fn place_order(
+        ctx: Context<PlaceOrder>,
+        outcome_index: u8,
+        side: OrderSide,
+        price: u64,
+        quantity: u64,
+    ) -> Result<()> {
+        instructions::place_order(ctx, outcome_index, side, price, quantity)
+    }
+
+    /// Cancel a limit order and return its escrow
+    pub fn cancel_order(ctx: Context<CancelOrder>) -> Result<()> {
+        instructions::cancel_order(ctx)
+    }
+
+    /// Match a crossing bid and ask
+    pub fn match_orders(ctx: Context<MatchOrders>) -> Result<()> {
+        instructions::match_orders(ctx)
+    }
+
+    /// Mint complete sets of outcome shares for USDC
+    pub fn mint_complete_set<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintCompleteSet<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::mint_complete_set(ctx, amount)
+    }
+
+    /// Burn complete sets of outcome shares back into USDC
+    pub fn burn_complete_set<'info>(
+        ctx: Context<'_, '_, '_, 'info, BurnCompleteSet<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::burn_complete_set(ctx, amount)
+    }
+
+    /// Create the share token mint for one outcome
+    pub fn initialize_outcome_mint(
+        ctx: Context<InitializeOutcomeMint>,
+        outcome_index: u8,
+    ) -> Result<()> {
+        instructions::initialize_outcome_mint(ctx, outcome_index)
+    }
+
     /// View function: Get market odds for all outcomes
     pub fn get_market_odds(ctx: Context<GetMarketOdds>) -> Result<Vec<OutcomeOdds>> {
         instructions::get_market_odds(ctx)
     }
 
     /// View function: Get user position in market
-    pub fn get_user_position(ctx: Context<GetUserPosition>) -> Result<UserPosition> {
+    pub fn get_user_position(ctx: Context<GetUserPosition>) -> Result<Vec<OutcomePosition>> {
         instructions::get_user_position(ctx)
     }
+
+    /// View function: Resolve a referrer code to its owner
+    pub fn get_referrer_code_owner(
+        ctx: Context<GetReferrerCodeOwner>,
+        referrer_code: String,
+    ) -> Result<ReferrerCodeOwner> {
+        instructions::get_referrer_code_owner(ctx, referrer_code)
+    }
 }
 
 #[event]
@@ -119,19 +305,64 @@ pub struct GlobalStateInitialized {
     pub platform_wallet: Pubkey,
 }
 
+#[event]
+pub struct MarketStatusChanged {
+    pub market: Pubkey,
+    pub previous: MarketStatus,
+    pub status: MarketStatus,
+    pub changed_at: i64,
+}
+
 #[event]
 pub struct UserProfileInitialized {
     pub user: Pubkey,
     pub referrer_code: String,
 }
 
+#[event]
+pub struct ReferrerCodeBackfilled {
+    pub user: Pubkey,
+    pub referrer_code: String,
+}
+
 #[event]
 pub struct MarketCreated {
     pub market: Pubkey,
+    pub market_id: u64,
     pub creator: Pubkey,
+    pub collateral_mint: Pubkey,
+    pub resolver: Pubkey,
     pub num_outcomes: u8,
     pub trading_fee_bps: u16,
     pub resolve_at: i64,
+    pub pricing_mode: PricingMode,
+    pub liquidity_param: u64,
+}
+
+#[event]
+pub struct OutcomeLabelsSet {
+    pub market: Pubkey,
+    pub start_index: u8,
+    pub labels: Vec<String>,
+}
+
+#[event]
+pub struct GlobalStateMigrated {
+    pub authority: Pubkey,
+    pub platform_wallet: Pubkey,
+}
+
+#[event]
+pub struct MarketMigrated {
+    pub legacy_market: Pubkey,
+    pub market: Pubkey,
+    pub market_id: u64,
+}
+
+#[event]
+pub struct MarketTagsUpdated {
+    pub market: Pubkey,
+    pub tags: Vec<String>,
 }
 
 #[event]
@@ -155,14 +386,17 @@ pub struct SharesSold {
 #[event]
 pub struct MarketResolved {
     pub market: Pubkey,
-    pub winning_outcome: u8,
+    pub payout_numerators: Vec<u32>,
+    pub payout_denominator: u32,
     pub resolved_at: i64,
+    pub dispute_ends_at: i64,
 }
 
 #[event]
 pub struct WinningsRedeemed {
     pub market: Pubkey,
     pub winner: Pubkey,
+    pub outcome_index: u8,
     pub amount_redeemed: u64,
 }
 
@@ -189,3 +423,176 @@ pub struct ProgramPaused {
 pub struct ProgramUnpaused {
     pub unpaused_at: i64,
 }
+
+#[event]
+pub struct OrderPlaced {
+    pub market: Pubkey,
+    pub owner: Pubkey,
+    pub order_id: u64,
+    pub outcome_index: u8,
+    pub side: OrderSide,
+    pub price: u64,
+    pub quantity: u64,
+}
+
+#[event]
+pub struct OrderCancelled {
+    pub market: Pubkey,
+    pub owner: Pubkey,
+    pub order_id: u64,
+    pub unfilled_quantity: u64,
+    pub escrow_returned: u64,
+}
+
+#[event]
+pub struct OrdersMatched {
+    pub market: Pubkey,
+    pub outcome_index: u8,
+    pub bid_id: u64,
+    pub ask_id: u64,
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub price: u64,
+    pub quantity: u64,
+    pub fee_amount: u64,
+}
+
+#[event]
+pub struct CompleteSetMinted {
+    pub market: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct CompleteSetBurned {
+    pub market: Pubkey,
+    pub user: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct OutcomeMintInitialized {
+    pub market: Pubkey,
+    pub outcome_index: u8,
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct CollateralMintAdded {
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct CollateralMintRemoved {
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct MarketVoided {
+    pub market: Pubkey,
+    pub reason: VoidReason,
+    pub peg_forfeited: u64,
+    pub voided_at: i64,
+}
+
+#[event]
+pub struct RefundClaimed {
+    pub market: Pubkey,
+    pub holder: Pubkey,
+    pub net_cost: u64,
+    pub amount_refunded: u64,
+}
+
+#[event]
+pub struct DisputeConfigUpdated {
+    pub dispute_period_secs: i64,
+    pub dispute_bond: u64,
+    pub arbiter: Pubkey,
+}
+
+#[event]
+pub struct ResolutionChallenged {
+    pub market: Pubkey,
+    pub challenger: Pubkey,
+    pub bond: u64,
+}
+
+#[event]
+pub struct DisputeResolved {
+    pub market: Pubkey,
+    pub challenger: Pubkey,
+    pub overturned: bool,
+    pub payout_numerators: Vec<u32>,
+    pub payout_denominator: u32,
+}
+
+#[event]
+pub struct OutcomeProposed {
+    pub market: Pubkey,
+    pub proposer: Pubkey,
+    pub payout_numerators: Vec<u32>,
+    pub payout_denominator: u32,
+    pub bond: u64,
+    pub liveness_ends_at: i64,
+}
+
+#[event]
+pub struct ProposalSettled {
+    pub market: Pubkey,
+    pub proposer: Pubkey,
+    pub bond_returned: u64,
+    pub reward: u64,
+}
+
+#[event]
+pub struct MarketResolvedFromPrice {
+    pub market: Pubkey,
+    pub price_feed: Pubkey,
+    pub price: i64,
+    pub expo: i32,
+    pub publish_time: i64,
+    pub winning_outcome: u8,
+}
+
+#[event]
+pub struct MarketResolvedFromAttestation {
+    pub market: Pubkey,
+    pub publisher: Pubkey,
+    pub event_id: String,
+    pub winning_outcome: u8,
+    pub attested_at: i64,
+}
+
+#[event]
+pub struct ResolverCouncilCreated {
+    pub council: Pubkey,
+    pub authority: Pubkey,
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct ResolutionVoteSubmitted {
+    pub market: Pubkey,
+    pub council: Pubkey,
+    pub member: Pubkey,
+    pub payout_numerators: Vec<u32>,
+    pub payout_denominator: u32,
+    pub matching_votes: u8,
+}
+
+#[event]
+pub struct ResolutionVoteMismatch {
+    pub market: Pubkey,
+    pub council: Pubkey,
+    pub member: Pubkey,
+    pub conflicting_member: Pubkey,
+}
+
+#[event]
+pub struct MarketClosed {
+    pub market: Pubkey,
+    pub creator: Pubkey,
+    pub dust_swept: u64,
+}
@@ -0,0 +1,178 @@
+//! Fixed product market maker (CPMM) pricing
+//!
+//! The pool holds a balance of every outcome's shares, created by minting
+//! complete sets (one share of each outcome per unit of collateral). Trades keep
+//! the product of the pool balances from decreasing:
+//!
+//! - a buy of `x` collateral mints `x` complete sets into the pool, then takes
+//!   shares of the chosen outcome out until the product is restored;
+//! - a sell of `s` shares adds them to the pool, then burns `r` complete sets
+//!   out of the pool for the largest `r` that keeps the product intact.
+//!
+//! Intermediate rounding always favours the pool. All functions return `None`
+//! when a result cannot be represented.
+
+use super::fixed_point;
+
+/// Iterations of the sell binary search (enough to cover any u64 amount)
+const SELL_SEARCH_STEPS: u32 = 64;
+
+/// Shares of `outcome_idx` received for paying `amount` into the pool
+pub fn shares_for_amount(balances: &[u64], outcome_idx: usize, amount: u64) -> Option<u64> {
+    let balance = *balances.get(outcome_idx)?;
+    if amount == 0 || balances.contains(&0) {
+        return None;
+    }
+
+    // ending_balance = balance * prod_{j != i} b_j / (b_j + amount), rounded up
+    let mut ending_balance = balance as u128;
+    for (j, &other) in balances.iter().enumerate() {
+        if j == outcome_idx {
+            continue;
+        }
+        ending_balance = fixed_point::mul_div_ceil(
+            ending_balance,
+            other as u128,
+            (other as u128).checked_add(amount as u128)?,
+        )?;
+    }
+
+    let received = (balance as u128)
+        .checked_add(amount as u128)?
+        .checked_sub(ending_balance)?;
+    u64::try_from(received).ok()
+}
+
+/// Whether burning `amount` complete sets after adding `shares` of `outcome_idx`
+/// leaves the pool's product at least as large as before
+fn can_burn(balances: &[u64], outcome_idx: usize, shares: u64, amount: u64) -> Option<bool> {
+    // (b_i + shares - amount) * prod_{j != i} (b_j - amount) / b_j >= b_i, rounded down
+    let balance = balances[outcome_idx] as u128;
+    let mut scaled = match (balance + shares as u128).checked_sub(amount as u128) {
+        Some(value) => value,
+        None => return Some(false),
+    };
+    for (j, &other) in balances.iter().enumerate() {
+        if j == outcome_idx {
+            continue;
+        }
+        if amount >= other {
+            return Some(false);
+        }
+        scaled = fixed_point::mul_div_floor(scaled, (other - amount) as u128, other as u128)?;
+    }
+    Some(scaled >= balance)
+}
+
+/// Collateral returned for selling `shares` of `outcome_idx` back to the pool
+pub fn amount_for_shares(balances: &[u64], outcome_idx: usize, shares: u64) -> Option<u64> {
+    balances.get(outcome_idx)?;
+    if shares == 0 || balances.contains(&0) {
+        return None;
+    }
+
+    // Binary search for the largest burn the invariant allows
+    let mut low: u64 = 0;
+    let mut high: u64 = shares;
+    for _ in 0..SELL_SEARCH_STEPS {
+        if low >= high {
+            break;
+        }
+        let mid = low + (high - low).div_ceil(2);
+        if can_burn(balances, outcome_idx, shares, mid)? {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+    Some(low)
+}
+
+/// Instantaneous prices in basis points, summing to exactly 10000
+///
+/// The price of outcome `i` is `(1 / b_i) / sum_j (1 / b_j)`.
+pub fn prices_bps(balances: &[u64]) -> Option<Vec<u64>> {
+    let mut weights = Vec::with_capacity(balances.len());
+    for &balance in balances {
+        if balance == 0 {
+            return None;
+        }
+        weights.push((1u128 << 120) / balance as u128);
+    }
+    super::weights_to_bps(&weights)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn product(balances: &[u64]) -> u128 {
+        balances.iter().map(|&balance| balance as u128).product()
+    }
+
+    fn buy(balances: &[u64], outcome: usize, amount: u64) -> Vec<u64> {
+        let received = shares_for_amount(balances, outcome, amount).unwrap();
+        let mut after: Vec<u64> = balances.iter().map(|&balance| balance + amount).collect();
+        after[outcome] -= received;
+        after
+    }
+
+    fn sell(balances: &[u64], outcome: usize, shares: u64) -> Vec<u64> {
+        let returned = amount_for_shares(balances, outcome, shares).unwrap();
+        let mut after = balances.to_vec();
+        after[outcome] += shares;
+        after.iter().map(|&balance| balance - returned).collect()
+    }
+
+    #[test]
+    fn buys_keep_the_product() {
+        let pools: [&[u64]; 3] = [&[1_000_000, 1_000_000], &[5_000_000, 700_000, 2_000_000], &[3, 7, 11]];
+        for balances in pools {
+            for outcome in 0..balances.len() {
+                for amount in [1, 999, 250_000, 10_000_000] {
+                    let after = buy(balances, outcome, amount);
+                    assert!(product(&after) >= product(balances), "{balances:?} buy {amount} of {outcome}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sells_keep_the_product() {
+        let pools: [&[u64]; 2] = [&[1_000_000, 1_000_000], &[5_000_000, 700_000, 2_000_000]];
+        for balances in pools {
+            for outcome in 0..balances.len() {
+                for shares in [1, 999, 250_000, 3_000_000] {
+                    let after = sell(balances, outcome, shares);
+                    assert!(product(&after) >= product(balances), "{balances:?} sell {shares} of {outcome}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn buy_then_sell_never_pays_out_more_than_paid_in() {
+        let balances = [2_000_000, 900_000, 4_000_000];
+        for outcome in 0..balances.len() {
+            for amount in [1, 12_345, 1_000_000, 50_000_000] {
+                let received = shares_for_amount(&balances, outcome, amount).unwrap();
+                let after = buy(&balances, outcome, amount);
+                let returned = amount_for_shares(&after, outcome, received).unwrap();
+                assert!(returned <= amount, "outcome {outcome}: paid {amount}, got back {returned}");
+            }
+        }
+    }
+
+    #[test]
+    fn prices_sum_to_one() {
+        let prices = prices_bps(&[1_000, 3_000, 6_000]).unwrap();
+        assert_eq!(prices.iter().sum::<u64>(), 10_000);
+        assert!(prices[0] > prices[1] && prices[1] > prices[2]);
+    }
+
+    #[test]
+    fn empty_pools_cannot_trade() {
+        assert_eq!(shares_for_amount(&[0, 10], 1, 5), None);
+        assert_eq!(amount_for_shares(&[10, 0], 0, 5), None);
+    }
+}
@@ -0,0 +1,271 @@
+//! Unsigned Q64.64 fixed-point arithmetic
+//!
+//! Values are stored in a `u128` where the upper 64 bits hold the integer part
+//! and the lower 64 bits hold the fraction. Everything here is integer-only so
+//! results are identical on every validator.
+
+/// 1.0 in Q64.64
+pub const ONE: u128 = 1 << 64;
+
+/// ln(2) in Q64.64
+pub const LN_2: u128 = 12_786_308_645_202_655_659;
+
+/// Maximum number of series terms evaluated by `exp` and `ln`
+const MAX_SERIES_TERMS: u128 = 64;
+
+/// Rounding direction for results that cannot be represented exactly
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Rounding {
+    Down,
+    Up,
+}
+
+/// Convert an integer to Q64.64
+pub fn from_u64(value: u64) -> u128 {
+    (value as u128) << 64
+}
+
+/// Full 256-bit product of two u128 values as (high, low)
+fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_hi, a_lo) = (a >> 64, a & MASK);
+    let (b_hi, b_lo) = (b >> 64, b & MASK);
+
+    let lo_lo = a_lo * b_lo;
+    let hi_lo = a_hi * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_hi = a_hi * b_hi;
+
+    let cross = (lo_lo >> 64) + (hi_lo & MASK) + (lo_hi & MASK);
+    let low = (cross << 64) | (lo_lo & MASK);
+    let high = hi_hi + (hi_lo >> 64) + (lo_hi >> 64) + (cross >> 64);
+    (high, low)
+}
+
+/// Compute `a * b / c` with a 256-bit intermediate, returning (quotient, remainder)
+fn mul_div_rem(a: u128, b: u128, c: u128) -> Option<(u128, u128)> {
+    if c == 0 {
+        return None;
+    }
+    let (high, low) = widening_mul(a, b);
+    if high == 0 {
+        return Some((low / c, low % c));
+    }
+    if high >= c {
+        return None;
+    }
+
+    // Shift-subtract long division of the 256-bit product
+    let mut remainder = high;
+    let mut quotient: u128 = 0;
+    for i in (0..128).rev() {
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((low >> i) & 1);
+        quotient <<= 1;
+        if carry == 1 || remainder >= c {
+            remainder = remainder.wrapping_sub(c);
+            quotient |= 1;
+        }
+    }
+    Some((quotient, remainder))
+}
+
+/// `a * b / c`, rounded down
+pub fn mul_div_floor(a: u128, b: u128, c: u128) -> Option<u128> {
+    mul_div_rem(a, b, c).map(|(quotient, _)| quotient)
+}
+
+/// `a * b / c`, rounded up
+pub fn mul_div_ceil(a: u128, b: u128, c: u128) -> Option<u128> {
+    let (quotient, remainder) = mul_div_rem(a, b, c)?;
+    if remainder == 0 {
+        Some(quotient)
+    } else {
+        quotient.checked_add(1)
+    }
+}
+
+/// `a * b / c` on token amounts with an explicit rounding direction
+///
+/// The intermediate product is kept in full precision, so the only loss is the
+/// final rounding step.
+pub fn mul_div_u64(a: u64, b: u64, c: u64, rounding: Rounding) -> Option<u64> {
+    let result = match rounding {
+        Rounding::Down => mul_div_floor(a as u128, b as u128, c as u128)?,
+        Rounding::Up => mul_div_ceil(a as u128, b as u128, c as u128)?,
+    };
+    u64::try_from(result).ok()
+}
+
+/// Q64.64 multiplication, rounded down
+pub fn mul(a: u128, b: u128) -> Option<u128> {
+    mul_div_floor(a, b, ONE)
+}
+
+/// Q64.64 division, rounded down
+pub fn div(a: u128, b: u128) -> Option<u128> {
+    mul_div_floor(a, ONE, b)
+}
+
+/// e^r for 0 <= r < ln(2), evaluated with a Taylor series
+fn exp_reduced(r: u128) -> Option<u128> {
+    let mut sum = ONE;
+    let mut term = ONE;
+    let mut n: u128 = 1;
+    while n <= MAX_SERIES_TERMS {
+        term = mul(term, r)? / n;
+        if term == 0 {
+            break;
+        }
+        sum = sum.checked_add(term)?;
+        n += 1;
+    }
+    Some(sum)
+}
+
+/// e^x for a non-negative Q64.64 exponent
+///
+/// Returns `None` once the result no longer fits in Q64.64 (x >= ~44).
+pub fn exp(x: u128) -> Option<u128> {
+    let k = x / LN_2;
+    let r = x - k * LN_2;
+    if k >= 63 {
+        return None;
+    }
+    exp_reduced(r)?.checked_mul(1 << k)
+}
+
+/// e^-x for a non-negative Q64.64 exponent
+///
+/// Underflows to zero for large exponents instead of failing.
+pub fn exp_neg(x: u128) -> Option<u128> {
+    let k = x / LN_2;
+    let r = x - k * LN_2;
+    if k >= 128 {
+        return Some(0);
+    }
+    Some(div(ONE, exp_reduced(r)?)? >> k)
+}
+
+/// Natural logarithm of a Q64.64 value >= 1
+pub fn ln(y: u128) -> Option<u128> {
+    if y < ONE {
+        return None;
+    }
+
+    // Range reduction: y = 2^k * m with 1 <= m < 2
+    let k = 127 - y.leading_zeros() - 64;
+    let m = y >> k;
+
+    // ln(m) = 2 * atanh(z) with z = (m - 1) / (m + 1) <= 1/3
+    let z = div(m - ONE, m + ONE)?;
+    let z_squared = mul(z, z)?;
+    let mut power = z;
+    let mut sum = z;
+    let mut n: u128 = 3;
+    while n <= 2 * MAX_SERIES_TERMS + 1 {
+        power = mul(power, z_squared)?;
+        let term = power / n;
+        if term == 0 {
+            break;
+        }
+        sum = sum.checked_add(term)?;
+        n += 2;
+    }
+
+    (k as u128).checked_mul(LN_2)?.checked_add(sum.checked_mul(2)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_f64(value: u128) -> f64 {
+        value as f64 / ONE as f64
+    }
+
+    fn from_f64(value: f64) -> u128 {
+        (value * ONE as f64) as u128
+    }
+
+    /// Within 1e-12 relative error, or a few Q64.64 ulps for tiny values
+    fn assert_close(actual: u128, expected: f64) {
+        let actual = to_f64(actual);
+        let error = (actual - expected).abs();
+        assert!(
+            error < 1e-12 * expected.abs() || error < 1e-18,
+            "got {actual}, expected {expected} (error {error})"
+        );
+    }
+
+    #[test]
+    fn mul_div_rounds_in_the_requested_direction() {
+        assert_eq!(mul_div_u64(10, 1, 3, Rounding::Down), Some(3));
+        assert_eq!(mul_div_u64(10, 1, 3, Rounding::Up), Some(4));
+        assert_eq!(mul_div_u64(9, 1, 3, Rounding::Down), Some(3));
+        assert_eq!(mul_div_u64(9, 1, 3, Rounding::Up), Some(3));
+        assert_eq!(mul_div_u64(0, 7, 3, Rounding::Up), Some(0));
+    }
+
+    #[test]
+    fn mul_div_keeps_full_precision() {
+        // The product overflows u64 but the result does not
+        assert_eq!(mul_div_u64(u64::MAX, u64::MAX, u64::MAX, Rounding::Down), Some(u64::MAX));
+        assert_eq!(mul_div_u64(u64::MAX, 3, 4, Rounding::Down), Some(13_835_058_055_282_163_711));
+        assert_eq!(mul_div_u64(u64::MAX, 3, 4, Rounding::Up), Some(13_835_058_055_282_163_712));
+        // The product overflows u128 but the result does not
+        assert_eq!(mul_div_floor(u128::MAX, u128::MAX, u128::MAX), Some(u128::MAX));
+        assert_eq!(mul_div_floor(u128::MAX, 6, 4), None);
+        assert_eq!(mul_div_ceil(u128::MAX - 1, 3, 3), Some(u128::MAX - 1));
+        assert_eq!(mul_div_floor(1 << 100, 1 << 100, 1 << 90), Some(1 << 110));
+        assert_eq!(mul_div_ceil((1 << 100) + 1, 1 << 100, 1 << 90), Some((1 << 110) + 1024));
+    }
+
+    #[test]
+    fn mul_div_rejects_zero_divisor_and_overflow() {
+        assert_eq!(mul_div_u64(1, 1, 0, Rounding::Down), None);
+        assert_eq!(mul_div_u64(u64::MAX, 2, 1, Rounding::Down), None);
+        assert_eq!(mul_div_ceil(u128::MAX, 1, 1), Some(u128::MAX));
+    }
+
+    #[test]
+    fn exp_matches_f64() {
+        for x in [0.0, 1e-9, 0.1, 0.5, LN_2 as f64 / ONE as f64, 1.0, 2.5, 10.0, 30.0, 43.0] {
+            assert_close(exp(from_f64(x)).unwrap(), x.exp());
+        }
+    }
+
+    #[test]
+    fn exp_overflows_to_none() {
+        assert_eq!(exp(from_u64(45)), None);
+    }
+
+    #[test]
+    fn exp_neg_matches_f64() {
+        for x in [0.0, 0.1, 1.0, 5.0, 20.0] {
+            assert_close(exp_neg(from_f64(x)).unwrap(), (-x).exp());
+        }
+        assert_eq!(exp_neg(from_u64(100)), Some(0));
+    }
+
+    #[test]
+    fn ln_matches_f64() {
+        assert_eq!(ln(ONE), Some(0));
+        for y in [1.5, 2.0, 2.718281828, 10.0, 1234.5, 1e12] {
+            assert_close(ln(from_f64(y)).unwrap(), y.ln());
+        }
+    }
+
+    #[test]
+    fn ln_rejects_values_below_one() {
+        assert_eq!(ln(ONE - 1), None);
+    }
+
+    #[test]
+    fn ln_inverts_exp() {
+        for x in [0.25, 1.0, 7.0, 20.0] {
+            let round_trip = ln(exp(from_f64(x)).unwrap()).unwrap();
+            assert_close(round_trip, x);
+        }
+    }
+}
@@ -0,0 +1,177 @@
+//! Logarithmic market scoring rule (LMSR) pricing
+//!
+//! The market maker's cost function is `C(q) = b * ln(sum_j e^(q_j / b))`, where
+//! `q_j` is the number of outstanding shares of outcome `j` and `b` is the
+//! liquidity parameter. A trade costs the change in `C`, and the instantaneous
+//! price of outcome `i` is `e^(q_i / b) / sum_j e^(q_j / b)`. Each winning share
+//! redeems for one unit of collateral, so the maker's worst-case loss is
+//! `b * ln(n)`, which the creator funds up front.
+//!
+//! All functions return `None` when a result cannot be represented.
+
+use super::fixed_point::{self, ONE};
+
+/// Exponential weights `e^((q_j - max q) / b)` and their sum
+///
+/// Shifting by the largest quantity keeps every exponent non-positive so the
+/// weights stay in (0, 1] regardless of how many shares are outstanding.
+fn weights(shares: &[u64], b: u64) -> Option<(Vec<u128>, u128)> {
+    if b == 0 {
+        return None;
+    }
+    let max_shares = *shares.iter().max()?;
+    let b_fixed = fixed_point::from_u64(b);
+
+    let mut weights = Vec::with_capacity(shares.len());
+    let mut sum: u128 = 0;
+    for &q in shares {
+        let exponent = fixed_point::div(fixed_point::from_u64(max_shares - q), b_fixed)?;
+        let weight = fixed_point::exp_neg(exponent)?;
+        sum = sum.checked_add(weight)?;
+        weights.push(weight);
+    }
+    Some((weights, sum))
+}
+
+/// Worst-case market maker loss, `b * ln(n)`, rounded up
+pub fn subsidy(b: u64, num_outcomes: u8) -> Option<u64> {
+    let ln_n = fixed_point::ln(fixed_point::from_u64(num_outcomes as u64))?;
+    let cost = fixed_point::mul_div_ceil(b as u128, ln_n, ONE)?;
+    u64::try_from(cost).ok()
+}
+
+/// Shares of `outcome_idx` received for paying `amount` into the market
+///
+/// Solves `C(q + d * e_i) - C(q) = amount` for `d`. With weights taken relative
+/// to the largest quantity `M`, `w_i = e^((q_i - M) / b)` and
+/// `d = (M - q_i) + b * ln(S * (e^(amount / b) - 1) + w_i)`, rounded down, which
+/// avoids dividing by the weight of an unlikely outcome.
+pub fn shares_for_amount(shares: &[u64], b: u64, outcome_idx: usize, amount: u64) -> Option<u64> {
+    let (weights, sum) = weights(shares, b)?;
+    let weight = *weights.get(outcome_idx)?;
+    let max_shares = *shares.iter().max()?;
+
+    let growth = fixed_point::exp(fixed_point::div(
+        fixed_point::from_u64(amount),
+        fixed_point::from_u64(b),
+    )?)?;
+    let scaled = fixed_point::mul(sum, growth - ONE)?.checked_add(weight)?;
+    let offset = fixed_point::from_u64(max_shares - shares[outcome_idx]);
+
+    // ln is only defined from one upwards, so small arguments use ln(x) = -ln(1 / x)
+    let received = if scaled >= ONE {
+        offset.checked_add((b as u128).checked_mul(fixed_point::ln(scaled)?)?)?
+    } else {
+        let inverse = fixed_point::mul_div_ceil(ONE, ONE, scaled)?;
+        offset.checked_sub((b as u128).checked_mul(fixed_point::ln(inverse)?)?)?
+    };
+    u64::try_from(received >> 64).ok()
+}
+
+/// Cost function `C(q) = max q + b * ln(sum_j e^((q_j - max q) / b))` in Q64.64
+///
+/// Evaluated relative to the largest quantity, where the weight sum is at least
+/// one, so differences between two costs do not cancel away the precision of
+/// small weights.
+fn cost(shares: &[u64], b: u64) -> Option<u128> {
+    let (_, sum) = weights(shares, b)?;
+    let max_shares = *shares.iter().max()?;
+    let log = fixed_point::ln(sum)?;
+    fixed_point::from_u64(max_shares).checked_add((b as u128).checked_mul(log)?)
+}
+
+/// Collateral returned for selling `amount` shares of `outcome_idx`
+///
+/// Computes `C(q) - C(q - d * e_i)`, rounded down.
+pub fn amount_for_shares(shares: &[u64], b: u64, outcome_idx: usize, amount: u64) -> Option<u64> {
+    let remaining = shares.get(outcome_idx)?.checked_sub(amount)?;
+    let mut after = shares.to_vec();
+    after[outcome_idx] = remaining;
+
+    let returned = cost(shares, b)?.saturating_sub(cost(&after, b)?) >> 64;
+    u64::try_from(returned).ok()
+}
+
+/// Instantaneous prices in basis points, summing to exactly 10000
+pub fn prices_bps(shares: &[u64], b: u64) -> Option<Vec<u64>> {
+    let (weights, _) = weights(shares, b)?;
+    super::weights_to_bps(&weights)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const B: u64 = 1_000_000_000;
+
+    #[test]
+    fn prices_sum_to_one() {
+        let books: [&[u64]; 4] = [
+            &[0, 0],
+            &[0, 0, 0],
+            &[5 * B, 0, 2 * B, 7],
+            &[40 * B, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+        ];
+        for shares in books {
+            let prices = prices_bps(shares, B).unwrap();
+            assert_eq!(prices.iter().sum::<u64>(), 10_000, "{shares:?}");
+        }
+    }
+
+    #[test]
+    fn prices_start_uniform() {
+        assert_eq!(prices_bps(&[0, 0, 0, 0], B).unwrap(), vec![2_500; 4]);
+    }
+
+    #[test]
+    fn buying_raises_the_price() {
+        let shares = [0, 0, 0];
+        let received = shares_for_amount(&shares, B, 1, B / 2).unwrap();
+        let prices = prices_bps(&[0, received, 0], B).unwrap();
+        assert!(prices[1] > 3_334);
+    }
+
+    #[test]
+    fn buy_then_sell_never_pays_out_more_than_paid_in() {
+        let books: [&[u64]; 4] = [&[0, 0], &[3 * B, B, 0], &[0, 20 * B, 0, 0, 0], &[0, 40 * B]];
+        for shares in books {
+            for outcome in 0..shares.len() {
+                for amount in [1, 1_000, B / 3, 2 * B, 25 * B] {
+                    let received = shares_for_amount(shares, B, outcome, amount).unwrap();
+                    let mut after = shares.to_vec();
+                    after[outcome] += received;
+                    let returned = amount_for_shares(&after, B, outcome, received).unwrap();
+                    assert!(
+                        returned <= amount,
+                        "{shares:?} outcome {outcome}: paid {amount}, got back {returned}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn buys_cost_at_most_what_was_paid() {
+        let shares = [0, 40 * B, 3 * B];
+        for outcome in 0..shares.len() {
+            for amount in [1, 1_000, B, 30 * B] {
+                let received = shares_for_amount(&shares, B, outcome, amount).unwrap();
+                let mut after = shares.to_vec();
+                after[outcome] += received;
+                let charged = (cost(&after, B).unwrap() - cost(&shares, B).unwrap()) >> 64;
+                assert!(charged <= amount as u128, "outcome {outcome}: paid {amount}, charged {charged}");
+            }
+        }
+    }
+
+    #[test]
+    fn subsidy_is_b_ln_n_rounded_up() {
+        assert_eq!(subsidy(B, 2), Some(693_147_181));
+        assert_eq!(subsidy(B, 10), Some(2_302_585_093));
+    }
+
+    #[test]
+    fn selling_more_than_outstanding_fails() {
+        assert_eq!(amount_for_shares(&[10, 0], B, 0, 11), None);
+    }
+}
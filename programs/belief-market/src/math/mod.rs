@@ -0,0 +1,58 @@
+pub mod cpmm;
+pub mod fixed_point;
+pub mod lmsr;
+
+/// Convert an amount in hundredths of a whole token into base units of a mint
+/// with `decimals` decimal places, rounding down
+pub fn collateral_amount(hundredths: u64, decimals: u8) -> Option<u64> {
+    let unit = 10u128.checked_pow(decimals as u32)?;
+    u64::try_from((hundredths as u128).checked_mul(unit)? / 100).ok()
+}
+
+/// Normalize relative weights into basis points summing to exactly 10000
+///
+/// Each share is rounded down and the leftover basis points go to the largest
+/// weight (lowest index on ties).
+pub fn weights_to_bps(weights: &[u128]) -> Option<Vec<u64>> {
+    let mut sum: u128 = 0;
+    for &weight in weights {
+        sum = sum.checked_add(weight)?;
+    }
+    if sum == 0 {
+        return None;
+    }
+
+    let mut prices = Vec::with_capacity(weights.len());
+    let mut allocated: u64 = 0;
+    let mut favourite = 0;
+    for (i, &weight) in weights.iter().enumerate() {
+        let price = fixed_point::mul_div_floor(weight, 10_000, sum)? as u64;
+        allocated += price;
+        if weight > weights[favourite] {
+            favourite = i;
+        }
+        prices.push(price);
+    }
+    prices[favourite] += 10_000 - allocated;
+    Some(prices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collateral_amount_scales_to_decimals() {
+        assert_eq!(collateral_amount(500, 6), Some(5_000_000));
+        assert_eq!(collateral_amount(180, 9), Some(1_800_000_000));
+        assert_eq!(collateral_amount(180, 0), Some(1));
+        assert_eq!(collateral_amount(10_000, 2), Some(10_000));
+    }
+
+    #[test]
+    fn collateral_amount_rejects_overflow() {
+        assert_eq!(collateral_amount(100, 20), None);
+        assert_eq!(collateral_amount(u64::MAX, 2), Some(u64::MAX));
+        assert_eq!(collateral_amount(u64::MAX, 3), None);
+    }
+}
@@ -0,0 +1,236 @@
+//! Verification of ed25519 signatures checked by the native ed25519 program
+//!
+//! Programs cannot verify ed25519 signatures cheaply themselves. Instead the
+//! transaction carries an ed25519 program instruction, which fails the whole
+//! transaction if its signature is invalid, and the program reads that
+//! instruction back from the instructions sysvar to confirm what was signed.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_LEN: usize = 14;
+const PUBKEY_LEN: usize = 32;
+const SIGNATURE_LEN: usize = 64;
+/// Instruction index meaning "this instruction" in the signature offsets
+const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+fn read_u16(data: &[u8], offset: usize) -> usize {
+    u16::from_le_bytes([data[offset], data[offset + 1]]) as usize
+}
+
+/// Check that the instruction just before the current one is an ed25519 program
+/// instruction verifying a single signature by `signer` over exactly `message`
+/// The signature, key and message must all be embedded in that instruction.
+pub fn verify_signed_message(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require!(
+        current_index > 0,
+        crate::errors::BelievError::InvalidAttestation
+    );
+    let ix = load_instruction_at_checked(current_index as usize - 1, instructions_sysvar)?;
+    require!(
+        ix.program_id == ed25519_program::ID,
+        crate::errors::BelievError::InvalidAttestation
+    );
+
+    let data = &ix.data;
+    require!(
+        data.len() >= SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_LEN && data[0] == 1,
+        crate::errors::BelievError::InvalidAttestation
+    );
+
+    let offsets = SIGNATURE_OFFSETS_START;
+    let signature_offset = read_u16(data, offsets);
+    let signature_ix = read_u16(data, offsets + 2);
+    let pubkey_offset = read_u16(data, offsets + 4);
+    let pubkey_ix = read_u16(data, offsets + 6);
+    let message_offset = read_u16(data, offsets + 8);
+    let message_size = read_u16(data, offsets + 10);
+    let message_ix = read_u16(data, offsets + 12);
+
+    // Offsets pointing into other instructions would let the signed data differ
+    // from what is checked here
+    let current = CURRENT_INSTRUCTION as usize;
+    require!(
+        signature_ix == current && pubkey_ix == current && message_ix == current,
+        crate::errors::BelievError::InvalidAttestation
+    );
+    require!(
+        signature_offset + SIGNATURE_LEN <= data.len()
+            && pubkey_offset + PUBKEY_LEN <= data.len()
+            && message_offset + message_size <= data.len(),
+        crate::errors::BelievError::InvalidAttestation
+    );
+
+    require!(
+        &data[pubkey_offset..pubkey_offset + PUBKEY_LEN] == signer.as_ref()
+            && &data[message_offset..message_offset + message_size] == message,
+        crate::errors::BelievError::InvalidAttestation
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::BelievError;
+    use crate::state::DataPublisher;
+    use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+    use anchor_lang::solana_program::sysvar::instructions::{
+        construct_instructions_data, store_current_index, BorrowedAccountMeta, BorrowedInstruction,
+    };
+    use anchor_lang::solana_program::sysvar;
+    use solana_sdk::ed25519_instruction::new_ed25519_instruction;
+    use solana_sdk::signature::Keypair;
+
+    const TIMESTAMP: i64 = 1_700_000_000;
+
+    fn generate_publisher() -> ed25519_dalek::Keypair {
+        ed25519_dalek::Keypair::from_bytes(&Keypair::new().to_bytes()).unwrap()
+    }
+
+    fn publisher_key(keypair: &ed25519_dalek::Keypair) -> Pubkey {
+        Pubkey::new_from_array(keypair.public.to_bytes())
+    }
+
+    /// Stand-in for the `resolve_from_attestation` instruction that reads the sysvar
+    fn resolve_instruction(market: &Pubkey) -> Instruction {
+        Instruction::new_with_bytes(
+            crate::ID,
+            &[],
+            vec![AccountMeta::new(*market, false), AccountMeta::new_readonly(sysvar::instructions::ID, false)],
+        )
+    }
+
+    /// Instructions sysvar data for a transaction made of `instructions`,
+    /// executing the one at `current_index`
+    fn instructions_sysvar_data(instructions: &[Instruction], current_index: u16) -> Vec<u8> {
+        let borrowed: Vec<BorrowedInstruction> = instructions
+            .iter()
+            .map(|ix| BorrowedInstruction {
+                program_id: &ix.program_id,
+                accounts: ix
+                    .accounts
+                    .iter()
+                    .map(|meta| BorrowedAccountMeta {
+                        pubkey: &meta.pubkey,
+                        is_signer: meta.is_signer,
+                        is_writable: meta.is_writable,
+                    })
+                    .collect(),
+                data: &ix.data,
+            })
+            .collect();
+        let mut data = construct_instructions_data(&borrowed);
+        store_current_index(&mut data, current_index);
+        data
+    }
+
+    fn verify(sysvar_data: &mut [u8], signer: &Pubkey, message: &[u8]) -> Result<()> {
+        let key = sysvar::instructions::ID;
+        let owner = sysvar::ID;
+        let mut lamports = 0;
+        let info = AccountInfo::new(&key, false, false, &mut lamports, sysvar_data, &owner, false, 0);
+        verify_signed_message(&info, signer, message)
+    }
+
+    #[test]
+    fn accepts_the_publishers_attestation() {
+        let publisher = generate_publisher();
+        let market = Pubkey::new_unique();
+        let message = DataPublisher::attestation_message(&market, 2, TIMESTAMP);
+        let ed25519_ix = new_ed25519_instruction(&publisher, &message);
+
+        // The precompile itself accepts the signature
+        let feature_set = solana_sdk::feature_set::FeatureSet::all_enabled();
+        assert!(solana_sdk::ed25519_instruction::verify(&ed25519_ix.data, &[&ed25519_ix.data], &feature_set).is_ok());
+
+        let mut data = instructions_sysvar_data(&[ed25519_ix, resolve_instruction(&market)], 1);
+        assert!(verify(&mut data, &publisher_key(&publisher), &message).is_ok());
+    }
+
+    #[test]
+    fn rejects_an_attestation_from_another_signer() {
+        let publisher = generate_publisher();
+        let impostor = generate_publisher();
+        let market = Pubkey::new_unique();
+        let message = DataPublisher::attestation_message(&market, 0, TIMESTAMP);
+        let ed25519_ix = new_ed25519_instruction(&impostor, &message);
+
+        let mut data = instructions_sysvar_data(&[ed25519_ix, resolve_instruction(&market)], 1);
+        assert_eq!(
+            verify(&mut data, &publisher_key(&publisher), &message).err(),
+            Some(BelievError::InvalidAttestation.into())
+        );
+    }
+
+    #[test]
+    fn rejects_an_attestation_of_another_message() {
+        let publisher = generate_publisher();
+        let market = Pubkey::new_unique();
+        let signed = DataPublisher::attestation_message(&market, 0, TIMESTAMP);
+        let ed25519_ix = new_ed25519_instruction(&publisher, &signed);
+        let mut data = instructions_sysvar_data(&[ed25519_ix, resolve_instruction(&market)], 1);
+
+        // Another outcome, timestamp or market than the one signed
+        for claimed in [
+            DataPublisher::attestation_message(&market, 1, TIMESTAMP),
+            DataPublisher::attestation_message(&market, 0, TIMESTAMP + 1),
+            DataPublisher::attestation_message(&Pubkey::new_unique(), 0, TIMESTAMP),
+        ] {
+            assert_eq!(
+                verify(&mut data, &publisher_key(&publisher), &claimed).err(),
+                Some(BelievError::InvalidAttestation.into())
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_a_missing_ed25519_instruction() {
+        let publisher = generate_publisher();
+        let market = Pubkey::new_unique();
+        let message = DataPublisher::attestation_message(&market, 0, TIMESTAMP);
+
+        // Nothing precedes the resolving instruction
+        let mut alone = instructions_sysvar_data(&[resolve_instruction(&market)], 0);
+        assert_eq!(
+            verify(&mut alone, &publisher_key(&publisher), &message).err(),
+            Some(BelievError::InvalidAttestation.into())
+        );
+
+        // The preceding instruction is not an ed25519 program instruction
+        let mut other = new_ed25519_instruction(&publisher, &message);
+        other.program_id = Pubkey::new_unique();
+        let mut data = instructions_sysvar_data(&[other, resolve_instruction(&market)], 1);
+        assert_eq!(
+            verify(&mut data, &publisher_key(&publisher), &message).err(),
+            Some(BelievError::InvalidAttestation.into())
+        );
+    }
+
+    #[test]
+    fn rejects_signatures_read_from_another_instruction() {
+        let publisher = generate_publisher();
+        let market = Pubkey::new_unique();
+        let message = DataPublisher::attestation_message(&market, 0, TIMESTAMP);
+        let mut ed25519_ix = new_ed25519_instruction(&publisher, &message);
+        // Point the message at instruction 1 instead of the ed25519 instruction itself
+        let message_ix = SIGNATURE_OFFSETS_START + 12;
+        ed25519_ix.data[message_ix..message_ix + 2].copy_from_slice(&1u16.to_le_bytes());
+
+        let mut data = instructions_sysvar_data(&[ed25519_ix, resolve_instruction(&market)], 1);
+        assert_eq!(
+            verify(&mut data, &publisher_key(&publisher), &message).err(),
+            Some(BelievError::InvalidAttestation.into())
+        );
+    }
+}
@@ -0,0 +1,267 @@
+//! Reader for Pyth v2 price accounts
+//!
+//! Decodes the fields needed for resolution straight from the account data
+//! rather than through the Pyth SDK, which pins an older `solana-program`. The
+//! offsets follow the `#[repr(C)]` `PriceAccount` layout: a header, the EMA
+//! fields, the previous aggregate and finally the current aggregate price.
+//!
+//! Builds with the `mock-pyth` feature accept price accounts owned by any
+//! program, so local tests can create accounts with the same layout.
+
+use anchor_lang::prelude::*;
+
+/// Pyth oracle program that owns price accounts
+/// This should be replaced with the Pyth deployment on the target cluster
+pub const PYTH_PROGRAM_ID: Pubkey = pubkey!("FsJ3A3u2vn5cTVofAjvy6y5kwABJAqYWpe4975bi2epH");
+
+const MAGIC: u32 = 0xa1b2c3d4;
+const VERSION_2: u32 = 2;
+const ACCOUNT_TYPE_PRICE: u32 = 3;
+const STATUS_TRADING: u32 = 1;
+
+const MAGIC_OFFSET: usize = 0;
+const VERSION_OFFSET: usize = 4;
+const ACCOUNT_TYPE_OFFSET: usize = 8;
+const EXPO_OFFSET: usize = 20;
+const TIMESTAMP_OFFSET: usize = 96;
+const AGG_PRICE_OFFSET: usize = 208;
+const AGG_CONF_OFFSET: usize = 216;
+const AGG_STATUS_OFFSET: usize = 224;
+const AGG_END: usize = 240;
+
+/// Aggregate price published by a feed: `price * 10^expo` with confidence `conf`
+pub struct PriceReading {
+    pub price: i64,
+    pub conf: u64,
+    pub expo: i32,
+    pub publish_time: i64,
+}
+
+impl PriceReading {
+    /// Check the reading can resolve a market due at `resolve_at`: it must be
+    /// published within `MAX_PRICE_STALENESS_SECS` after resolution time, still
+    /// be fresh at `now` and have a confidence interval within
+    /// `MAX_PRICE_CONF_BPS` of the price
+    pub fn validate(&self, resolve_at: i64, now: i64) -> Result<()> {
+        require!(
+            self.publish_time >= resolve_at
+                && self.publish_time - resolve_at <= crate::constants::MAX_PRICE_STALENESS_SECS
+                && now - self.publish_time <= crate::constants::MAX_PRICE_STALENESS_SECS,
+            crate::errors::BelievError::StalePrice
+        );
+        require!(
+            (self.conf as u128) * 10000
+                <= (self.price.unsigned_abs() as u128) * crate::constants::MAX_PRICE_CONF_BPS as u128,
+            crate::errors::BelievError::PriceConfidenceTooWide
+        );
+        Ok(())
+    }
+}
+
+fn read_bytes<const N: usize>(data: &[u8], offset: usize) -> [u8; N] {
+    let mut bytes = [0u8; N];
+    bytes.copy_from_slice(&data[offset..offset + N]);
+    bytes
+}
+
+/// Read the current aggregate price from a Pyth price account
+/// Fails unless the account is a v2 price account whose aggregate is trading
+pub fn read_price(price_account: &AccountInfo) -> Result<PriceReading> {
+    #[cfg(not(feature = "mock-pyth"))]
+    require!(
+        price_account.owner == &PYTH_PROGRAM_ID,
+        crate::errors::BelievError::InvalidPriceFeed
+    );
+
+    let data = price_account.try_borrow_data()?;
+    require!(
+        data.len() >= AGG_END
+            && u32::from_le_bytes(read_bytes(&data, MAGIC_OFFSET)) == MAGIC
+            && u32::from_le_bytes(read_bytes(&data, VERSION_OFFSET)) == VERSION_2
+            && u32::from_le_bytes(read_bytes(&data, ACCOUNT_TYPE_OFFSET)) == ACCOUNT_TYPE_PRICE,
+        crate::errors::BelievError::InvalidPriceFeed
+    );
+    require!(
+        u32::from_le_bytes(read_bytes(&data, AGG_STATUS_OFFSET)) == STATUS_TRADING,
+        crate::errors::BelievError::PriceUnavailable
+    );
+
+    Ok(PriceReading {
+        price: i64::from_le_bytes(read_bytes(&data, AGG_PRICE_OFFSET)),
+        conf: u64::from_le_bytes(read_bytes(&data, AGG_CONF_OFFSET)),
+        expo: i32::from_le_bytes(read_bytes(&data, EXPO_OFFSET)),
+        publish_time: i64::from_le_bytes(read_bytes(&data, TIMESTAMP_OFFSET)),
+    })
+}
+
+/// Rescale `value * 10^from_expo` to an integer multiple of `10^to_expo`,
+/// truncating toward zero
+pub fn rescale(value: i64, from_expo: i32, to_expo: i32) -> Option<i64> {
+    let shift = from_expo.checked_sub(to_expo)?;
+    let factor = 10i128.checked_pow(shift.unsigned_abs())?;
+    let scaled = if shift >= 0 {
+        (value as i128).checked_mul(factor)?
+    } else {
+        (value as i128) / factor
+    };
+    i64::try_from(scaled).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{MAX_PRICE_CONF_BPS, MAX_PRICE_STALENESS_SECS};
+    use crate::errors::BelievError;
+    use crate::state::PriceBands;
+
+    const RESOLVE_AT: i64 = 1_700_000_000;
+
+    /// Price account data with the given aggregate, laid out like Pyth's `PriceAccount`
+    fn price_account_data(price: i64, conf: u64, expo: i32, publish_time: i64, status: u32) -> Vec<u8> {
+        let mut data = vec![0u8; AGG_END];
+        data[MAGIC_OFFSET..MAGIC_OFFSET + 4].copy_from_slice(&MAGIC.to_le_bytes());
+        data[VERSION_OFFSET..VERSION_OFFSET + 4].copy_from_slice(&VERSION_2.to_le_bytes());
+        data[ACCOUNT_TYPE_OFFSET..ACCOUNT_TYPE_OFFSET + 4].copy_from_slice(&ACCOUNT_TYPE_PRICE.to_le_bytes());
+        data[EXPO_OFFSET..EXPO_OFFSET + 4].copy_from_slice(&expo.to_le_bytes());
+        data[TIMESTAMP_OFFSET..TIMESTAMP_OFFSET + 8].copy_from_slice(&publish_time.to_le_bytes());
+        data[AGG_PRICE_OFFSET..AGG_PRICE_OFFSET + 8].copy_from_slice(&price.to_le_bytes());
+        data[AGG_CONF_OFFSET..AGG_CONF_OFFSET + 8].copy_from_slice(&conf.to_le_bytes());
+        data[AGG_STATUS_OFFSET..AGG_STATUS_OFFSET + 4].copy_from_slice(&status.to_le_bytes());
+        data
+    }
+
+    fn read_with_owner(data: &mut [u8], owner: &Pubkey) -> Result<PriceReading> {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let info = AccountInfo::new(&key, false, false, &mut lamports, data, owner, false, 0);
+        read_price(&info)
+    }
+
+    fn reading(price: i64, conf: u64, publish_time: i64) -> PriceReading {
+        PriceReading { price, conf, expo: -8, publish_time }
+    }
+
+    #[test]
+    fn reads_the_aggregate_price() {
+        let mut data = price_account_data(6_512_345_678_901, 1_000_000, -8, RESOLVE_AT, STATUS_TRADING);
+        let reading = read_with_owner(&mut data, &PYTH_PROGRAM_ID).unwrap();
+        assert_eq!(reading.price, 6_512_345_678_901);
+        assert_eq!(reading.conf, 1_000_000);
+        assert_eq!(reading.expo, -8);
+        assert_eq!(reading.publish_time, RESOLVE_AT);
+    }
+
+    #[test]
+    fn rejects_malformed_or_halted_accounts() {
+        let mut short = price_account_data(100, 0, -8, RESOLVE_AT, STATUS_TRADING);
+        short.truncate(AGG_END - 1);
+        assert_eq!(
+            read_with_owner(&mut short, &PYTH_PROGRAM_ID).err(),
+            Some(BelievError::InvalidPriceFeed.into())
+        );
+
+        let mut wrong_magic = price_account_data(100, 0, -8, RESOLVE_AT, STATUS_TRADING);
+        wrong_magic[MAGIC_OFFSET] ^= 0xff;
+        assert_eq!(
+            read_with_owner(&mut wrong_magic, &PYTH_PROGRAM_ID).err(),
+            Some(BelievError::InvalidPriceFeed.into())
+        );
+
+        let mut halted = price_account_data(100, 0, -8, RESOLVE_AT, 0);
+        assert_eq!(
+            read_with_owner(&mut halted, &PYTH_PROGRAM_ID).err(),
+            Some(BelievError::PriceUnavailable.into())
+        );
+    }
+
+    #[cfg(not(feature = "mock-pyth"))]
+    #[test]
+    fn rejects_accounts_not_owned_by_pyth() {
+        let mut data = price_account_data(100, 0, -8, RESOLVE_AT, STATUS_TRADING);
+        assert_eq!(
+            read_with_owner(&mut data, &Pubkey::new_unique()).err(),
+            Some(BelievError::InvalidPriceFeed.into())
+        );
+    }
+
+    #[cfg(feature = "mock-pyth")]
+    #[test]
+    fn mock_feeds_may_be_owned_by_any_program() {
+        let mut data = price_account_data(100, 0, -8, RESOLVE_AT, STATUS_TRADING);
+        assert!(read_with_owner(&mut data, &Pubkey::new_unique()).is_ok());
+    }
+
+    #[test]
+    fn accepts_fresh_confident_prices() {
+        assert!(reading(1_000_000, 10_000, RESOLVE_AT).validate(RESOLVE_AT, RESOLVE_AT).is_ok());
+        assert!(reading(-1_000_000, 10_000, RESOLVE_AT + 5)
+            .validate(RESOLVE_AT, RESOLVE_AT + 5 + MAX_PRICE_STALENESS_SECS)
+            .is_ok());
+    }
+
+    #[test]
+    fn rejects_stale_prices() {
+        // Published before resolution time
+        assert_eq!(
+            reading(1_000_000, 0, RESOLVE_AT - 1).validate(RESOLVE_AT, RESOLVE_AT).err(),
+            Some(BelievError::StalePrice.into())
+        );
+        // Too old at the resolving slot
+        assert_eq!(
+            reading(1_000_000, 0, RESOLVE_AT)
+                .validate(RESOLVE_AT, RESOLVE_AT + MAX_PRICE_STALENESS_SECS + 1)
+                .err(),
+            Some(BelievError::StalePrice.into())
+        );
+    }
+
+    #[test]
+    fn rejects_prices_published_long_after_resolution_time() {
+        let last = RESOLVE_AT + MAX_PRICE_STALENESS_SECS;
+        assert!(reading(1_000_000, 0, last).validate(RESOLVE_AT, last).is_ok());
+        // Fresh, but no longer the price at resolution time
+        assert_eq!(
+            reading(1_000_000, 0, last + 1).validate(RESOLVE_AT, last + 1).err(),
+            Some(BelievError::StalePrice.into())
+        );
+    }
+
+    #[test]
+    fn rejects_wide_confidence_intervals() {
+        let widest = 1_000_000 * MAX_PRICE_CONF_BPS / 10000;
+        assert!(reading(1_000_000, widest, RESOLVE_AT).validate(RESOLVE_AT, RESOLVE_AT).is_ok());
+        assert_eq!(
+            reading(1_000_000, widest + 1, RESOLVE_AT).validate(RESOLVE_AT, RESOLVE_AT).err(),
+            Some(BelievError::PriceConfidenceTooWide.into())
+        );
+        assert_eq!(
+            reading(-1_000_000, widest + 1, RESOLVE_AT).validate(RESOLVE_AT, RESOLVE_AT).err(),
+            Some(BelievError::PriceConfidenceTooWide.into())
+        );
+    }
+
+    #[test]
+    fn selects_the_band_containing_the_price() {
+        // Bands in whole dollars: < 60k, [60k, 70k), >= 70k
+        let bands = PriceBands {
+            feed: Pubkey::new_unique(),
+            expo: 0,
+            thresholds: vec![60_000, 70_000],
+        };
+        let band_of = |price: i64, expo: i32| bands.outcome_for(rescale(price, expo, bands.expo).unwrap());
+
+        assert_eq!(band_of(5_999_999_999_999, -8), 0);
+        assert_eq!(band_of(6_000_000_000_000, -8), 1);
+        assert_eq!(band_of(6_999_999_999_999, -8), 1);
+        assert_eq!(band_of(7_000_000_000_000, -8), 2);
+        assert_eq!(band_of(-5, 0), 0);
+    }
+
+    #[test]
+    fn rescale_truncates_toward_zero() {
+        assert_eq!(rescale(123_456, -3, 0), Some(123));
+        assert_eq!(rescale(-123_456, -3, 0), Some(-123));
+        assert_eq!(rescale(123, 0, -3), Some(123_000));
+        assert_eq!(rescale(i64::MAX, 0, -1), None);
+    }
+}
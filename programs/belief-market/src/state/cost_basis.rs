@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+/// Net collateral a holder has put into one market, refunded if the market is voided
+///
+/// Counts what the holder paid into the market's pools (buys, complete-set
+/// mints, filled bids) less what they took out (sells, complete-set burns,
+/// filled asks). Trading fees are excluded, and the basis never goes below
+/// zero: a holder who has taken out more than they put in is owed nothing.
+#[account]
+pub struct CostBasis {
+    /// Market the basis is held in
+    pub market: Pubkey,
+    /// Holder the basis belongs to
+    pub owner: Pubkey,
+    /// Net collateral paid in, in collateral base units
+    pub net_cost: u64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl CostBasis {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 1;
+
+    /// Fill in a basis account created by `init_if_needed`; no-op once set
+    pub fn init_if_new(&mut self, market: Pubkey, owner: Pubkey, bump: u8) {
+        if self.market == Pubkey::default() {
+            self.market = market;
+            self.owner = owner;
+            self.bump = bump;
+        }
+    }
+}
@@ -10,14 +10,42 @@ pub struct GlobalState {
     pub platform_wallet: Pubkey,
     /// Whether the program is paused
     pub paused: bool,
+    /// Mints markets may use as collateral
+    pub collateral_mints: Vec<Pubkey>,
+    /// Seconds after resolution during which the outcome can be challenged
+    pub dispute_period_secs: i64,
+    /// Bond a challenger posts, in hundredths of a collateral token
+    pub dispute_bond: u64,
+    /// Rules on challenged resolutions
+    pub arbiter: Pubkey,
+    /// Number of market IDs assigned so far; the next market gets `market_count + 1`
+    pub market_count: u64,
     /// Bump seed for PDA derivation
     pub bump: u8,
 }
 
 impl GlobalState {
-    pub const LEN: usize = 8 + 32 + 32 + 1 + 1;
+    pub const LEN: usize = 8 + 32 + 32 + 1 + 4 + 32 * crate::constants::MAX_COLLATERAL_MINTS + 8 + 8 + 32 + 8 + 1;
 
     pub fn is_paused(&self) -> bool {
         self.paused
     }
+
+    pub fn is_collateral_allowed(&self, mint: &Pubkey) -> bool {
+        self.collateral_mints.contains(mint)
+    }
+
+    /// Dispute bond in base units of a collateral mint with `decimals` decimal places
+    pub fn dispute_bond_amount(&self, decimals: u8) -> Result<u64> {
+        Ok(crate::math::collateral_amount(self.dispute_bond, decimals)
+            .ok_or(crate::errors::BelievError::ArithmeticOverflow)?)
+    }
+
+    /// Assign the next market ID
+    pub fn next_market_id(&mut self) -> Result<u64> {
+        self.market_count = self.market_count
+            .checked_add(1)
+            .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+        Ok(self.market_count)
+    }
 }
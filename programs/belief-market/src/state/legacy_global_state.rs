@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use super::GlobalState;
+
+/// Layout of the global state written by the original program, before the
+/// collateral allowlist, dispute configuration and market counter
+///
+/// Only read by `migrate_global_state`; the account carries the `GlobalState`
+/// discriminator.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LegacyGlobalState {
+    /// Program authority that can pause/unpause
+    pub authority: Pubkey,
+    /// Wallet to collect platform fees
+    pub platform_wallet: Pubkey,
+    /// Whether the program is paused
+    pub paused: bool,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl LegacyGlobalState {
+    pub const LEN: usize = 8 + 32 + 32 + 1 + 1;
+
+    /// Deserialize the legacy global state, checking its owner, discriminator and size
+    pub fn load(info: &AccountInfo) -> Result<Self> {
+        require!(
+            info.owner == &crate::ID,
+            crate::errors::BelievError::InvalidGlobalState
+        );
+        let data = info.try_borrow_data()?;
+        require!(
+            data.len() == Self::LEN && data[..8] == GlobalState::DISCRIMINATOR,
+            crate::errors::BelievError::InvalidGlobalState
+        );
+        Ok(Self::deserialize(&mut &data[8..])?)
+    }
+}
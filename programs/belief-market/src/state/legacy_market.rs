@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use super::Market;
+
+/// Layout of markets created by the original program under the
+/// `[MARKET_SEED, creator, resolve_at]` seeds
+///
+/// These markets predate the collateral allowlist, vault PDAs, outcome share
+/// mints, pricing modes and the status machine: they are parimutuel markets
+/// whose collateral sits in a token account owned by the market PDA, and whose
+/// shares are plain counters. Only read by `migrate_market`; these accounts
+/// carry the `Market` discriminator.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LegacyMarket {
+    /// Market creator
+    pub creator: Pubkey,
+    /// User who invited the creator
+    pub invitor: Option<Pubkey>,
+    /// User who referred the creator
+    pub referrer: Option<Pubkey>,
+    /// Number of outcomes (2-10)
+    pub num_outcomes: u8,
+    /// Labels for each outcome (max 20 chars each)
+    pub outcome_labels: Vec<String>,
+    /// Total amount wagered on each outcome (in lamports)
+    pub outcome_pools: Vec<u64>,
+    /// Number of shares issued for each outcome
+    pub outcome_shares: Vec<u64>,
+    /// Market tags for filtering
+    pub tags: Vec<String>,
+    /// Trading fee in basis points (1-500)
+    pub trading_fee_bps: u16,
+    /// Timestamp when market resolves
+    pub resolve_at: i64,
+    /// Whether market has been resolved
+    pub resolved: bool,
+    /// Winning outcome index (only set after resolution)
+    pub winning_outcome: Option<u8>,
+    /// Creator peg amount (1 USDC in lamports)
+    pub creator_peg_amount: u64,
+    /// Whether creator has claimed their peg
+    pub creator_peg_claimed: bool,
+    /// Accumulated fees per outcome (not yet withdrawn)
+    pub accumulated_fees: Vec<u64>,
+    /// Market creation timestamp
+    pub created_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl LegacyMarket {
+    /// Most outcomes the original program allowed
+    pub const MAX_OUTCOMES: u8 = 10;
+
+    /// Deserialize a legacy market account, checking its owner and discriminator
+    pub fn load(info: &AccountInfo) -> Result<Self> {
+        require!(
+            info.owner == &crate::ID,
+            crate::errors::BelievError::InvalidMarketState
+        );
+        let data = info.try_borrow_data()?;
+        require!(
+            data.len() > 8 && data[..8] == Market::DISCRIMINATOR,
+            crate::errors::BelievError::InvalidMarketState
+        );
+        let legacy_market = Self::deserialize(&mut &data[8..])?;
+
+        let num_outcomes = legacy_market.num_outcomes as usize;
+        require!(
+            (Market::MIN_OUTCOMES..=Self::MAX_OUTCOMES).contains(&legacy_market.num_outcomes)
+                && legacy_market.outcome_labels.len() == num_outcomes
+                && legacy_market.outcome_pools.len() == num_outcomes
+                && legacy_market.outcome_shares.len() == num_outcomes
+                && legacy_market.accumulated_fees.len() == num_outcomes,
+            crate::errors::BelievError::InvalidMarketState
+        );
+        Ok(legacy_market)
+    }
+
+    /// Exact account space of this market once migrated to the current layout
+    pub fn market_space(&self) -> usize {
+        Market::space(&self.tags, &None, &None)
+    }
+
+    /// Payout vector of a resolved market: everything to the winning outcome
+    pub fn payout_numerators(&self) -> Result<Option<Vec<u32>>> {
+        if !self.resolved {
+            return Ok(None);
+        }
+        let winning_outcome = self.winning_outcome
+            .filter(|&outcome| outcome < self.num_outcomes)
+            .ok_or(crate::errors::BelievError::InvalidMarketState)?;
+        let mut payout_numerators = vec![0; self.num_outcomes as usize];
+        payout_numerators[winning_outcome as usize] = 1;
+        Ok(Some(payout_numerators))
+    }
+}
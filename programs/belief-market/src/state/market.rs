@@ -1,38 +1,218 @@
 use anchor_lang::prelude::*;
+use crate::math::fixed_point::{self, Rounding};
+use super::{CostBasis, OutcomeBook};
+
+/// How a market prices outcome shares
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PricingMode {
+    /// Shares priced from each outcome's pool; winners split the total pool
+    Parimutuel,
+    /// Logarithmic market scoring rule; each winning share redeems for 1 unit
+    Lmsr,
+    /// Fixed product market maker seeded by the creator; each winning share redeems for 1 unit
+    Cpmm,
+}
+
+/// Price bands of a market resolved from a Pyth price feed
+///
+/// Outcome `i` wins when the feed's price lies in `[thresholds[i - 1], thresholds[i])`,
+/// with the first and last bands open-ended, so `n` outcomes need `n - 1`
+/// ascending thresholds. Thresholds are integers scaled by `10^expo`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct PriceBands {
+    /// Pyth price account the market resolves from
+    pub feed: Pubkey,
+    /// Decimal exponent of the thresholds
+    pub expo: i32,
+    /// Ascending band boundaries
+    pub thresholds: Vec<i64>,
+}
+
+impl PriceBands {
+    /// Serialized size of these bands
+    pub fn space(&self) -> usize {
+        32 + 4 + 4 + 8 * self.thresholds.len()
+    }
+
+    /// Outcome whose band contains `price` (scaled by `10^expo`)
+    pub fn outcome_for(&self, price: i64) -> usize {
+        self.thresholds.iter().filter(|&&threshold| price >= threshold).count()
+    }
+}
+
+/// Data vendor whose signed result resolves a market
+///
+/// The publisher attests to a result by signing the 41-byte message
+/// `market || outcome_index (u8) || timestamp (i64 LE)` with its ed25519 key.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct DataPublisher {
+    /// Publisher's ed25519 signing key
+    pub publisher: Pubkey,
+    /// Publisher's identifier for the event (max 32 chars)
+    pub event_id: String,
+}
+
+impl DataPublisher {
+    /// Serialized size of this publisher
+    pub fn space(&self) -> usize {
+        32 + 4 + self.event_id.len()
+    }
+
+    /// Message the publisher signs to attest that `outcome_index` won `market`
+    pub fn attestation_message(market: &Pubkey, outcome_index: u8, timestamp: i64) -> Vec<u8> {
+        let mut message = Vec::with_capacity(32 + 1 + 8);
+        message.extend_from_slice(market.as_ref());
+        message.push(outcome_index);
+        message.extend_from_slice(&timestamp.to_le_bytes());
+        message
+    }
+}
+
+/// Lifecycle of a market
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MarketStatus {
+    /// Created, waiting for every outcome mint to be initialized
+    Draft,
+    /// Trading until `resolve_at`
+    Open,
+    /// Trading halted, waiting for resolution
+    Closed,
+    /// Outcome proposed by a bonded proposer, within its dispute window
+    Proposed,
+    /// Outcome set by the resolver, within its dispute window
+    Resolved,
+    /// Resolution challenged, waiting for the arbiter
+    Disputed,
+    /// Voided without a winner; holders claim refunds
+    Voided,
+    /// Resolution is final; payouts are open
+    Finalized,
+}
+
+impl MarketStatus {
+    /// Whether the market has not been resolved or voided yet
+    pub fn is_unresolved(&self) -> bool {
+        matches!(self, MarketStatus::Draft | MarketStatus::Open | MarketStatus::Closed)
+    }
+
+    /// Whether the market has reached a terminal status and payouts are open
+    pub fn is_settled(&self) -> bool {
+        matches!(self, MarketStatus::Voided | MarketStatus::Finalized)
+    }
+
+    /// Whether a market in this status may move to `next`
+    pub fn can_transition_to(&self, next: MarketStatus) -> bool {
+        use MarketStatus::*;
+        match (*self, next) {
+            (Draft, Open) | (Draft | Open, Closed) => true,
+            (Draft | Open | Closed, Proposed | Resolved | Voided | Finalized) => true,
+            (Proposed | Resolved, Disputed) => true,
+            // An arbiter confirming a proposal sends it back to be settled
+            (Disputed, Proposed) => true,
+            (Proposed | Resolved | Disputed, Finalized) => true,
+            // A proposal or dispute still pending past the resolution deadline
+            (Proposed | Disputed, Voided) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Why a market was voided instead of resolved
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VoidReason {
+    /// The question was ambiguous or cannot be answered as written
+    Ambiguous,
+    /// The event never took place
+    EventCancelled,
+    /// The authority delisted the market
+    Delisted,
+    /// Nobody resolved the market before its resolution deadline
+    ResolutionExpired,
+}
+
+impl VoidReason {
+    /// Whether the creator loses their peg; an event falling through or a missing
+    /// resolver is not their fault
+    pub fn forfeits_creator_peg(&self) -> bool {
+        !matches!(self, VoidReason::EventCancelled | VoidReason::ResolutionExpired)
+    }
+}
 
 /// Represents a single prediction market
+/// Per-outcome state lives in the market's `OutcomeBook`
 #[account]
 pub struct Market {
+    /// Sequential market ID from `GlobalState::market_count`, seeding the market PDA
+    pub market_id: u64,
+    /// Address the market had before it was migrated to its ID-seeded PDA;
+    /// orders placed before the migration stay derived from it
+    pub legacy_address: Option<Pubkey>,
     /// Market creator
     pub creator: Pubkey,
     /// User who invited the creator
     pub invitor: Option<Pubkey>,
     /// User who referred the creator
     pub referrer: Option<Pubkey>,
-    /// Number of outcomes (2-10)
+    /// Token mint the market is denominated in
+    pub collateral_mint: Pubkey,
+    /// Decimals of the collateral mint, shared by the outcome share mints
+    pub collateral_decimals: u8,
+    /// Token account holding the market's collateral, owned by the market PDA
+    pub vault: Pubkey,
+    /// Zero-copy account holding the market's per-outcome state
+    pub outcome_book: Pubkey,
+    /// Key allowed to resolve the market
+    pub resolver: Pubkey,
+    /// Number of outcomes (2-200; at most 10 for LMSR and CPMM markets)
     pub num_outcomes: u8,
-    /// Labels for each outcome (max 20 chars each)
-    pub outcome_labels: Vec<String>,
-    /// Total amount wagered on each outcome (in lamports)
-    pub outcome_pools: Vec<u64>,
-    /// Number of shares issued for each outcome
-    pub outcome_shares: Vec<u64>,
     /// Market tags for filtering
     pub tags: Vec<String>,
+    /// Price bands for markets resolved from a Pyth feed (None for other markets)
+    pub price_bands: Option<PriceBands>,
+    /// Data publisher for markets resolved from signed attestations (None for other markets)
+    pub publisher: Option<DataPublisher>,
     /// Trading fee in basis points (1-500)
     pub trading_fee_bps: u16,
+    /// Pricing mechanism used for buys and sells
+    pub pricing_mode: PricingMode,
+    /// LMSR liquidity parameter `b`, or the initial CPMM liquidity (0 for parimutuel markets)
+    pub liquidity_param: u64,
+    /// Creator-funded liquidity still held by the market vault
+    pub liquidity_amount: u64,
     /// Timestamp when market resolves
     pub resolve_at: i64,
-    /// Whether market has been resolved
-    pub resolved: bool,
-    /// Winning outcome index (only set after resolution)
-    pub winning_outcome: Option<u8>,
-    /// Creator peg amount (1 USDC in lamports)
+    /// Timestamp after which an unresolved market can be voided by anyone
+    pub resolution_deadline: i64,
+    /// Where the market is in its lifecycle
+    pub status: MarketStatus,
+    /// Denominator of the payout numerators in the outcome book (0 until resolution)
+    pub payout_denominator: u32,
+    /// End of the window in which the resolution can be challenged
+    pub dispute_ends_at: i64,
+    /// Timestamp of the publisher attestation the market is resolved from (0 until then)
+    pub attested_at: i64,
+    /// User who challenged the resolution, while the challenge awaits the arbiter
+    pub challenger: Option<Pubkey>,
+    /// Bond posted by the challenger and held in the vault
+    pub challenge_bond: u64,
+    /// User who proposed the resolution, until the proposal is settled
+    pub proposer: Option<Pubkey>,
+    /// Bond posted by the proposer and held in the vault
+    pub proposal_bond: u64,
+    /// Creator peg amount (in collateral base units)
     pub creator_peg_amount: u64,
     /// Whether creator has claimed their peg
     pub creator_peg_claimed: bool,
-    /// Accumulated fees per outcome (not yet withdrawn)
-    pub accumulated_fees: Vec<u64>,
+    /// Why the market was voided (only set for voided markets)
+    pub void_reason: Option<VoidReason>,
+    /// Id assigned to the next limit order
+    pub next_order_id: u64,
+    /// Collateral escrowed in the vault by open bids
+    pub order_escrow: u64,
+    /// Outcome shares escrowed by open asks, burned until filled or cancelled
+    pub ask_escrow: u64,
+    /// Sum of every holder's `CostBasis::net_cost`, refunded if the market is voided
+    pub total_cost_basis: u64,
     /// Market creation timestamp
     pub created_at: i64,
     /// Bump seed for PDA derivation
@@ -40,27 +220,559 @@ pub struct Market {
 }
 
 impl Market {
-    pub const MAX_OUTCOMES: u8 = 10;
+    /// Exact account space (including the discriminator) for a market with these
+    /// variable-length fields; only edits to the tags change the size
+    pub fn space(
+        tags: &[String],
+        price_bands: &Option<PriceBands>,
+        publisher: &Option<DataPublisher>,
+    ) -> usize {
+        8 // discriminator
+            + 8 + (1 + 32) // market_id, legacy_address
+            + 32 + (1 + 32) + (1 + 32) // creator, invitor, referrer
+            + 32 + 1 + 32 + 32 + 32 // collateral_mint, collateral_decimals, vault, outcome_book, resolver
+            + 1 // num_outcomes
+            + 4 + tags.iter().map(|tag| 4 + tag.len()).sum::<usize>()
+            + 1 + price_bands.as_ref().map_or(0, PriceBands::space)
+            + 1 + publisher.as_ref().map_or(0, DataPublisher::space)
+            + 2 + 1 + 8 + 8 // trading_fee_bps, pricing_mode, liquidity_param, liquidity_amount
+            + 8 + 8 + 1 + 4 // resolve_at, resolution_deadline, status, payout_denominator
+            + 8 + 8 + (1 + 32) + 8 // dispute_ends_at, attested_at, challenger, challenge_bond
+            + (1 + 32) + 8 // proposer, proposal_bond
+            + 8 + 1 + (1 + 1) // creator_peg_amount, creator_peg_claimed, void_reason
+            + 8 + 8 + 8 + 8 // next_order_id, order_escrow, ask_escrow, total_cost_basis
+            + 8 + 1 // created_at, bump
+    }
+
+    pub const MAX_OUTCOMES: u8 = crate::constants::MAX_OUTCOMES;
     pub const MIN_OUTCOMES: u8 = 2;
     pub const MAX_OUTCOME_LABEL_LEN: usize = 20;
     pub const MAX_TAGS: usize = 5;
     pub const MAX_TAG_LEN: usize = 15;
 
     /// Calculate the current odds for a specific outcome (in basis points, 0-10000 = 0%-100%)
-    pub fn get_outcome_odds(&self, outcome_idx: usize) -> u64 {
-        if self.outcome_pools.is_empty() {
+    pub fn get_outcome_odds(&self, book: &OutcomeBook, outcome_idx: usize) -> u64 {
+        if book.outcome_count() == 0 {
             return 0;
         }
-        let total_pool: u64 = self.outcome_pools.iter().sum();
+        match self.pricing_mode {
+            PricingMode::Parimutuel => {}
+            PricingMode::Lmsr => {
+                return crate::math::lmsr::prices_bps(book.shares(), self.liquidity_param)
+                    .map(|prices| prices[outcome_idx])
+                    .unwrap_or(0);
+            }
+            PricingMode::Cpmm => {
+                return crate::math::cpmm::prices_bps(book.balances())
+                    .map(|prices| prices[outcome_idx])
+                    .unwrap_or(0);
+            }
+        }
+        let total_pool = book.total_pool();
         if total_pool == 0 {
-            return 10000 / self.outcome_pools.len() as u64;
+            return 10000 / book.outcome_count() as u64;
         }
-        (self.outcome_pools[outcome_idx] as u128 * 10000 as u128 / total_pool as u128) as u64
+        let weights: Vec<u128> = book.pools().iter().map(|&pool| pool as u128).collect();
+        crate::math::weights_to_bps(&weights)
+            .map(|odds| odds[outcome_idx])
+            .unwrap_or(0)
+    }
+
+    /// Address its orders are derived from: the pre-migration address for
+    /// migrated markets, otherwise the market's own `address`
+    pub fn order_seed_address(&self, address: Pubkey) -> Pubkey {
+        self.legacy_address.unwrap_or(address)
+    }
+
+    /// Whether the market was voided instead of resolved to a winner
+    pub fn is_voided(&self) -> bool {
+        self.status == MarketStatus::Voided
     }
 
-    /// Get total amount in all outcome pools
-    pub fn total_pool(&self) -> u64 {
-        self.outcome_pools.iter().sum()
+    /// Whether shares can currently be traded
+    pub fn is_trading(&self, now: i64) -> bool {
+        self.status == MarketStatus::Open && now < self.resolve_at
+    }
+
+    /// Move the market to `next`, failing unless the lifecycle allows it
+    pub fn set_status(&mut self, market: Pubkey, next: MarketStatus, now: i64) -> Result<()> {
+        require!(
+            self.status.can_transition_to(next),
+            crate::errors::BelievError::InvalidStatusTransition
+        );
+        let previous = self.status;
+        self.status = next;
+
+        emit!(crate::MarketStatusChanged {
+            market,
+            previous,
+            status: next,
+            changed_at: now,
+        });
+        Ok(())
+    }
+
+    /// Finalize a resolved market once its dispute window has closed unchallenged
+    pub fn finalize_if_due(&mut self, market: Pubkey, now: i64) -> Result<()> {
+        if self.status == MarketStatus::Resolved && now >= self.dispute_ends_at {
+            self.set_status(market, MarketStatus::Finalized, now)?;
+        }
+        Ok(())
+    }
+
+    /// Share token mint of an outcome, failing if it has not been created yet
+    pub fn outcome_mint(&self, book: &OutcomeBook, outcome_idx: usize) -> Result<Pubkey> {
+        let mint = *book.mints()
+            .get(outcome_idx)
+            .ok_or(crate::errors::BelievError::InvalidOutcomeIndex)?;
+        require!(
+            mint != Pubkey::default(),
+            crate::errors::BelievError::OutcomeMintNotInitialized
+        );
+        Ok(mint)
+    }
+
+    /// Trading fee charged on `amount`, rounded up so the vault never undercharges
+    pub fn trading_fee(&self, amount: u64) -> Result<u64> {
+        Ok(fixed_point::mul_div_u64(amount, self.trading_fee_bps as u64, 10000, Rounding::Up)
+            .ok_or(crate::errors::BelievError::ArithmeticOverflow)?)
+    }
+
+    /// Record a trading fee charged on an outcome; `withdraw_fees` later splits
+    /// the whole fee between the creator, invitor and platform
+    pub fn accrue_fee(&self, book: &mut OutcomeBook, outcome_idx: usize, fee_amount: u64) -> Result<()> {
+        book.accumulated_fees[outcome_idx] = book.accumulated_fees[outcome_idx]
+            .checked_add(fee_amount)
+            .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Split `total_fees` into the creator, invitor and platform shares: 80%, 10%
+    /// and 10%, rounded down for the creator and invitor. Without an invitor, the
+    /// invitor's share goes to the platform.
+    pub fn fee_shares(&self, total_fees: u64) -> Result<(u64, u64, u64)> {
+        let percent_of = |percent: u16| {
+            fixed_point::mul_div_u64(total_fees, percent as u64, 100, Rounding::Down)
+                .ok_or(crate::errors::BelievError::ArithmeticOverflow)
+        };
+        let creator_share = percent_of(crate::constants::FEE_CREATOR_PERCENT)?;
+        let invitor_share = if self.invitor.is_some() {
+            percent_of(crate::constants::FEE_INVITOR_PERCENT)?
+        } else {
+            0
+        };
+        let platform_share = total_fees - creator_share - invitor_share;
+        Ok((creator_share, invitor_share, platform_share))
+    }
+
+    /// Remove `amount` from the accumulated fees, outcome by outcome
+    pub fn take_fees(&self, book: &mut OutcomeBook, amount: u64) -> Result<()> {
+        let mut remaining = amount;
+        let num_outcomes = book.outcome_count();
+        for fees in book.accumulated_fees[..num_outcomes].iter_mut() {
+            let taken = remaining.min(*fees);
+            *fees -= taken;
+            remaining -= taken;
+        }
+        require!(
+            remaining == 0,
+            crate::errors::BelievError::InsufficientFunds
+        );
+        Ok(())
+    }
+
+    /// Shares issued for paying `amount` (after fees) into an outcome
+    pub fn quote_buy(&self, book: &OutcomeBook, outcome_idx: usize, amount: u64) -> Result<u64> {
+        match self.pricing_mode {
+            PricingMode::Parimutuel => {
+                // Parimutuel model: shares = amount / current_price
+                // Current price for an outcome is: outcome_pool / outcome_shares (if shares > 0, else 1)
+                if book.outcome_shares[outcome_idx] == 0 || book.outcome_pools[outcome_idx] == 0 {
+                    // First buyer: 1 share per lamport
+                    return Ok(amount);
+                }
+
+                // shares = amount * outcome_shares / outcome_pool, rounded down
+                Ok(fixed_point::mul_div_u64(
+                    amount,
+                    book.outcome_shares[outcome_idx],
+                    book.outcome_pools[outcome_idx],
+                    Rounding::Down,
+                )
+                .ok_or(crate::errors::BelievError::MarketCalculationError)?)
+            }
+            PricingMode::Lmsr => Ok(crate::math::lmsr::shares_for_amount(
+                book.shares(),
+                self.liquidity_param,
+                outcome_idx,
+                amount,
+            )
+            .ok_or(crate::errors::BelievError::MarketCalculationError)?),
+            PricingMode::Cpmm => Ok(crate::math::cpmm::shares_for_amount(
+                book.balances(),
+                outcome_idx,
+                amount,
+            )
+            .ok_or(crate::errors::BelievError::MarketCalculationError)?),
+        }
+    }
+
+    /// Collateral value (before fees) of selling shares of an outcome
+    pub fn quote_sell(&self, book: &OutcomeBook, outcome_idx: usize, shares: u64) -> Result<u64> {
+        match self.pricing_mode {
+            // value = shares * outcome_pool / outcome_shares, rounded down
+            PricingMode::Parimutuel => Ok(fixed_point::mul_div_u64(
+                shares,
+                book.outcome_pools[outcome_idx],
+                book.outcome_shares[outcome_idx],
+                Rounding::Down,
+            )
+            .ok_or(crate::errors::BelievError::MarketCalculationError)?),
+            PricingMode::Lmsr => Ok(crate::math::lmsr::amount_for_shares(
+                book.shares(),
+                self.liquidity_param,
+                outcome_idx,
+                shares,
+            )
+            .ok_or(crate::errors::BelievError::MarketCalculationError)?),
+            PricingMode::Cpmm => Ok(crate::math::cpmm::amount_for_shares(
+                book.balances(),
+                outcome_idx,
+                shares,
+            )
+            .ok_or(crate::errors::BelievError::MarketCalculationError)?),
+        }
+    }
+
+    /// Collateral paid out for redeeming `shares` of a resolved outcome: the
+    /// outcome's reserved payout split pro-rata between its shares, rounded down
+    pub fn quote_redemption(&self, book: &OutcomeBook, outcome_idx: usize, shares: u64) -> Result<u64> {
+        if book.outcome_shares[outcome_idx] == 0 {
+            return Ok(0);
+        }
+        Ok(fixed_point::mul_div_u64(
+            shares,
+            book.payout_pools[outcome_idx],
+            book.outcome_shares[outcome_idx],
+            Rounding::Down,
+        )
+        .ok_or(crate::errors::BelievError::MarketCalculationError)?)
+    }
+
+    /// Check a payout vector has one numerator per outcome and the numerators
+    /// sum to a non-zero denominator
+    pub fn validate_payout_vector(&self, payout_numerators: &[u32], payout_denominator: u32) -> Result<()> {
+        require!(
+            payout_numerators.len() == self.num_outcomes as usize,
+            crate::errors::BelievError::InvalidPayoutVector
+        );
+        let numerator_sum = payout_numerators
+            .iter()
+            .try_fold(0u32, |sum, &numerator| sum.checked_add(numerator))
+            .ok_or(crate::errors::BelievError::InvalidPayoutVector)?;
+        require!(
+            payout_denominator > 0 && numerator_sum == payout_denominator,
+            crate::errors::BelievError::InvalidPayoutVector
+        );
+        Ok(())
+    }
+
+    /// Resolve the market: outcome `i` pays `payout_numerators[i] / payout_denominator`
+    ///
+    /// Parimutuel markets split the whole pool between outcomes with holders in
+    /// proportion to their numerators; if no paying outcome has holders, each
+    /// outcome's pool goes back to its own holders instead. In LMSR and CPMM
+    /// markets each share of outcome `i` pays `payout_numerators[i] /
+    /// payout_denominator` collateral.
+    /// Callers move the market to its post-resolution status themselves.
+    pub fn resolve(
+        &mut self,
+        book: &mut OutcomeBook,
+        payout_numerators: &[u32],
+        payout_denominator: u32,
+    ) -> Result<()> {
+        self.validate_payout_vector(payout_numerators, payout_denominator)?;
+
+        match self.pricing_mode {
+            PricingMode::Parimutuel => {
+                let total_pool = book.total_pool();
+                let held_numerators: u64 = payout_numerators
+                    .iter()
+                    .zip(book.shares())
+                    .filter(|(_, &shares)| shares > 0)
+                    .map(|(&numerator, _)| numerator as u64)
+                    .sum();
+                for (i, &numerator) in payout_numerators.iter().enumerate() {
+                    book.payout_pools[i] = if book.outcome_shares[i] == 0 {
+                        0
+                    } else if held_numerators == 0 {
+                        book.outcome_pools[i]
+                    } else {
+                        fixed_point::mul_div_u64(total_pool, numerator as u64, held_numerators, Rounding::Down)
+                            .ok_or(crate::errors::BelievError::MarketCalculationError)?
+                    };
+                }
+            }
+            PricingMode::Lmsr | PricingMode::Cpmm => {
+                for (i, &numerator) in payout_numerators.iter().enumerate() {
+                    book.payout_pools[i] = fixed_point::mul_div_u64(
+                        book.outcome_shares[i],
+                        numerator as u64,
+                        payout_denominator as u64,
+                        Rounding::Down,
+                    )
+                    .ok_or(crate::errors::BelievError::MarketCalculationError)?;
+                }
+            }
+        }
+
+        book.payout_numerators[..payout_numerators.len()].copy_from_slice(payout_numerators);
+        self.payout_denominator = payout_denominator;
+        Ok(())
+    }
+
+    /// Discard a pending resolution's payout vector; voided markets refund from the pools
+    pub fn clear_resolution(&mut self, book: &mut OutcomeBook) {
+        book.payout_pools.fill(0);
+        book.payout_numerators.fill(0);
+        self.payout_denominator = 0;
+    }
+
+    /// Check a publisher attestation signed at `timestamp` may resolve the market
+    ///
+    /// Attestations must be signed between `resolve_at` and now. The first one
+    /// submitted resolves the market and opens its dispute window; until that
+    /// window closes or the resolution is challenged, an attestation with a later
+    /// timestamp replaces it. The latest signed attestation therefore wins, so a
+    /// publisher can correct a result by signing a new one.
+    pub fn check_attestation(&self, timestamp: i64, now: i64) -> Result<()> {
+        require!(
+            timestamp >= self.resolve_at && timestamp <= now,
+            crate::errors::BelievError::InvalidAttestationTimestamp
+        );
+        if self.status.is_unresolved() {
+            return Ok(());
+        }
+        require!(
+            self.status == MarketStatus::Resolved,
+            crate::errors::BelievError::MarketAlreadyResolved
+        );
+        require!(
+            now < self.dispute_ends_at,
+            crate::errors::BelievError::DisputeWindowClosed
+        );
+        require!(
+            timestamp > self.attested_at,
+            crate::errors::BelievError::InvalidAttestationTimestamp
+        );
+        Ok(())
+    }
+
+    /// Open the dispute window on a freshly resolved market
+    pub fn start_dispute_window(&mut self, now: i64, dispute_period_secs: i64) -> Result<()> {
+        self.dispute_ends_at = now
+            .checked_add(dispute_period_secs)
+            .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Record collateral a holder paid into the pools
+    pub fn add_cost_basis(&mut self, position: &mut CostBasis, amount: u64) -> Result<()> {
+        position.net_cost = position.net_cost
+            .checked_add(amount)
+            .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+        self.total_cost_basis = self.total_cost_basis
+            .checked_add(amount)
+            .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Record collateral a holder took out of the pools, stopping at a zero basis
+    pub fn remove_cost_basis(&mut self, position: &mut CostBasis, amount: u64) {
+        let removed = amount.min(position.net_cost);
+        position.net_cost -= removed;
+        self.total_cost_basis -= removed;
+    }
+
+    /// Refund owed to a holder with `net_cost` basis in a voided market
+    ///
+    /// Each holder gets their net cost back. Refunds are paid from the pools
+    /// only, so if holders who took out more than they put in have left the
+    /// pools short of the total basis, every refund is scaled down by the same
+    /// ratio, rounded down.
+    pub fn quote_refund(&self, book: &OutcomeBook, net_cost: u64) -> Result<u64> {
+        if self.total_cost_basis == 0 {
+            return Ok(0);
+        }
+        let refundable = book.total_pool().min(self.total_cost_basis);
+        Ok(fixed_point::mul_div_u64(
+            net_cost,
+            refundable,
+            self.total_cost_basis,
+            Rounding::Down,
+        )
+        .ok_or(crate::errors::BelievError::MarketCalculationError)?)
+    }
+
+    /// Update CPMM pool balances after a buy: `amount` complete sets are minted
+    /// into the pool and `shares_out` of the outcome leave it
+    pub fn settle_amm_buy(&self, book: &mut OutcomeBook, outcome_idx: usize, amount: u64, shares_out: u64) -> Result<()> {
+        if self.pricing_mode != PricingMode::Cpmm {
+            return Ok(());
+        }
+        let num_outcomes = book.outcome_count();
+        for balance in book.amm_balances[..num_outcomes].iter_mut() {
+            *balance = balance
+                .checked_add(amount)
+                .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+        }
+        book.amm_balances[outcome_idx] = book.amm_balances[outcome_idx]
+            .checked_sub(shares_out)
+            .ok_or(crate::errors::BelievError::MarketCalculationError)?;
+        Ok(())
+    }
+
+    /// Update CPMM pool balances after a sell: `shares_in` of the outcome enter
+    /// the pool and `amount` complete sets are burned out of it
+    pub fn settle_amm_sell(&self, book: &mut OutcomeBook, outcome_idx: usize, shares_in: u64, amount: u64) -> Result<()> {
+        if self.pricing_mode != PricingMode::Cpmm {
+            return Ok(());
+        }
+        book.amm_balances[outcome_idx] = book.amm_balances[outcome_idx]
+            .checked_add(shares_in)
+            .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+        let num_outcomes = book.outcome_count();
+        for balance in book.amm_balances[..num_outcomes].iter_mut() {
+            *balance = balance
+                .checked_sub(amount)
+                .ok_or(crate::errors::BelievError::MarketCalculationError)?;
+        }
+        Ok(())
+    }
+
+    /// Whether each winning share is backed by exactly one unit of collateral
+    pub fn uses_complete_sets(&self) -> bool {
+        matches!(self.pricing_mode, PricingMode::Lmsr | PricingMode::Cpmm)
+    }
+
+    /// Record `amount` complete sets minted against `amount` collateral
+    pub fn mint_complete_sets(&self, book: &mut OutcomeBook, amount: u64) -> Result<()> {
+        require!(
+            self.uses_complete_sets(),
+            crate::errors::BelievError::CompleteSetsUnsupported
+        );
+        let num_outcomes = book.outcome_count();
+        for shares in book.outcome_shares[..num_outcomes].iter_mut() {
+            *shares = shares
+                .checked_add(amount)
+                .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+        }
+
+        // Spread the collateral evenly; only the pools' total backs complete sets
+        let num_pools = num_outcomes as u64;
+        for (i, pool) in book.outcome_pools[..num_outcomes].iter_mut().enumerate() {
+            let mut deposit = amount / num_pools;
+            if i == 0 {
+                deposit += amount % num_pools;
+            }
+            *pool = pool
+                .checked_add(deposit)
+                .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+        }
+        Ok(())
+    }
+
+    /// Record `amount` complete sets burned and their collateral released
+    pub fn burn_complete_sets(&mut self, book: &mut OutcomeBook, amount: u64) -> Result<()> {
+        require!(
+            self.uses_complete_sets(),
+            crate::errors::BelievError::CompleteSetsUnsupported
+        );
+        let num_outcomes = book.outcome_count();
+        for shares in book.outcome_shares[..num_outcomes].iter_mut() {
+            *shares = shares
+                .checked_sub(amount)
+                .ok_or(crate::errors::BelievError::InsufficientShares)?;
+        }
+        self.withdraw_from_pools(book, 0, amount)
+    }
+
+    /// Remove collateral paid out of the market from its pools
+    ///
+    /// Draws from the given outcome's pool first, then from the other pools,
+    /// then from creator-funded liquidity. Fails if the market cannot cover it.
+    pub fn withdraw_from_pools(&mut self, book: &mut OutcomeBook, outcome_idx: usize, amount: u64) -> Result<()> {
+        // Most payouts fit in the outcome's own pool; avoid walking every outcome
+        if book.outcome_pools[outcome_idx] >= amount {
+            book.outcome_pools[outcome_idx] -= amount;
+            return Ok(());
+        }
+
+        let available = book.total_pool()
+            .checked_add(self.liquidity_amount)
+            .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+        require!(
+            available >= amount,
+            crate::errors::BelievError::InsufficientFunds
+        );
+
+        let mut remaining = amount;
+        let num_pools = book.outcome_count();
+        for offset in 0..num_pools {
+            let idx = (outcome_idx + offset) % num_pools;
+            let taken = remaining.min(book.outcome_pools[idx]);
+            book.outcome_pools[idx] -= taken;
+            remaining -= taken;
+        }
+        self.liquidity_amount -= remaining;
+        Ok(())
+    }
+
+    /// Creator liquidity no longer needed to back outstanding paying shares
+    pub fn liquidity_refund(&self, book: &OutcomeBook) -> Result<u64> {
+        // Refunds are paid from the pools, so all creator liquidity is left over,
+        // along with pool collateral beyond what is owed to cost bases: liquidity
+        // traders drew on when they sold at a profit
+        if self.is_voided() {
+            return self.liquidity_amount
+                .checked_add(book.total_pool().saturating_sub(self.total_cost_basis))
+                .ok_or(crate::errors::BelievError::ArithmeticOverflow.into());
+        }
+        if !self.uses_complete_sets() {
+            return Ok(0);
+        }
+        let available = book.total_pool()
+            .checked_add(self.liquidity_amount)
+            .ok_or(crate::errors::BelievError::ArithmeticOverflow)?;
+        let reserved: u64 = book.payouts().iter().sum();
+        Ok(available.saturating_sub(reserved))
+    }
+
+    /// Whether anyone is still owed collateral from the vault: refunds of cost
+    /// bases, payouts for outcome tokens still in circulation, the creator's peg
+    /// and liquidity, fees, open bids or asks, or bonds
+    ///
+    /// `supplies` holds the current supply of each outcome's mint. Holders are
+    /// owed only for tokens that still exist, so payouts reserved for tokens
+    /// burned outside the program do not hold the market open.
+    pub fn has_outstanding_liabilities(&self, book: &OutcomeBook, supplies: &[u64]) -> Result<bool> {
+        let mut owed_to_holders = self.is_voided() && self.total_cost_basis > 0;
+        if !self.is_voided() {
+            for (i, &supply) in supplies.iter().enumerate() {
+                owed_to_holders |= self.quote_redemption(book, i, supply)? > 0;
+            }
+        }
+        let owed_to_creator = !self.creator_peg_claimed
+            && (self.creator_peg_amount > 0 || self.liquidity_refund(book)? > 0);
+
+        Ok(owed_to_holders
+            || owed_to_creator
+            || book.total_fees() > 0
+            || self.order_escrow > 0
+            // Shares escrowed by asks are out of the mint supply but still owed
+            || self.ask_escrow > 0
+            || self.challenge_bond > 0
+            || self.proposal_bond > 0)
     }
 
     /// Validate market state
@@ -70,17 +782,242 @@ impl Market {
             crate::errors::BelievError::InvalidOutcomeCount
         );
         require!(
-            self.outcome_labels.len() == self.num_outcomes as usize,
-            crate::errors::BelievError::OutcomeCountMismatch
-        );
-        require!(
-            self.outcome_pools.len() == self.num_outcomes as usize,
-            crate::errors::BelievError::OutcomeCountMismatch
+            !self.uses_complete_sets() || self.num_outcomes <= crate::constants::MAX_AMM_OUTCOMES,
+            crate::errors::BelievError::InvalidOutcomeCount
         );
         require!(
             self.trading_fee_bps <= 500,
             crate::errors::BelievError::InvalidTradingFee
         );
+        require!(
+            self.pricing_mode == PricingMode::Parimutuel || self.liquidity_param > 0,
+            crate::errors::BelievError::InvalidLiquidityParam
+        );
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::__private::bytemuck::Zeroable;
+
+    /// An all-zero market: every enum at its first variant and every option empty
+    fn blank_market() -> Market {
+        Market::deserialize(&mut &[0u8; 2048][..]).unwrap()
+    }
+
+    fn voided_market_and_book(pools: &[u64]) -> (Market, Box<OutcomeBook>) {
+        let (mut market, book) = parimutuel_book(pools, &[]);
+        market.status = MarketStatus::Voided;
+        (market, book)
+    }
+
+    fn parimutuel_book(pools: &[u64], shares: &[u64]) -> (Market, Box<OutcomeBook>) {
+        let mut market = blank_market();
+        market.num_outcomes = pools.len() as u8;
+        let mut book = Box::new(OutcomeBook::zeroed());
+        book.num_outcomes = pools.len() as u16;
+        book.outcome_pools[..pools.len()].copy_from_slice(pools);
+        book.outcome_shares[..shares.len()].copy_from_slice(shares);
+        (market, book)
+    }
+
+    fn position(net_cost: u64) -> CostBasis {
+        CostBasis {
+            market: Pubkey::default(),
+            owner: Pubkey::default(),
+            net_cost,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn cost_basis_tracks_total_and_stops_at_zero() {
+        let mut market = blank_market();
+        let mut alice = position(0);
+        let mut bob = position(0);
+
+        market.add_cost_basis(&mut alice, 300).unwrap();
+        market.add_cost_basis(&mut bob, 200).unwrap();
+        assert_eq!(market.total_cost_basis, 500);
+
+        // Selling above cost only clears the holder's own basis
+        market.remove_cost_basis(&mut alice, 450);
+        assert_eq!(alice.net_cost, 0);
+        assert_eq!(bob.net_cost, 200);
+        assert_eq!(market.total_cost_basis, 200);
+    }
+
+    #[test]
+    fn refund_returns_net_cost_when_pools_cover_it() {
+        let (mut market, book) = voided_market_and_book(&[700, 300]);
+        market.total_cost_basis = 800;
+
+        // A holder who bought one outcome is refunded what they paid, not its pool share
+        assert_eq!(market.quote_refund(&book, 500).unwrap(), 500);
+        assert_eq!(market.quote_refund(&book, 300).unwrap(), 300);
+        // The surplus goes back to the creator
+        assert_eq!(market.liquidity_refund(&book).unwrap(), 200);
+    }
+
+    #[test]
+    fn refund_is_scaled_down_when_pools_fall_short() {
+        let (mut market, book) = voided_market_and_book(&[400, 200]);
+        market.total_cost_basis = 900;
+
+        assert_eq!(market.quote_refund(&book, 600).unwrap(), 400);
+        assert_eq!(market.quote_refund(&book, 300).unwrap(), 200);
+        assert_eq!(market.quote_refund(&book, 1).unwrap(), 0);
+        assert_eq!(market.liquidity_refund(&book).unwrap(), 0);
+    }
+
+    #[test]
+    fn outstanding_basis_blocks_close_of_voided_market() {
+        let (mut market, book) = voided_market_and_book(&[0, 0]);
+        market.total_cost_basis = 1;
+        assert!(market.has_outstanding_liabilities(&book, &[0, 0]).unwrap());
+
+        market.total_cost_basis = 0;
+        assert!(!market.has_outstanding_liabilities(&book, &[0, 0]).unwrap());
+    }
+
+    #[test]
+    fn resting_ask_blocks_close() {
+        let (mut market, mut book) = parimutuel_book(&[600, 400], &[600, 400]);
+        market.resolve(&mut book, &[1, 0], 1).unwrap();
+        market.creator_peg_claimed = true;
+
+        // Every winning share sits burned in an ask, so the mint supply is zero
+        market.ask_escrow = 600;
+        assert!(market.has_outstanding_liabilities(&book, &[0, 400]).unwrap());
+
+        market.ask_escrow = 0;
+        assert!(!market.has_outstanding_liabilities(&book, &[0, 400]).unwrap());
+    }
+
+    #[test]
+    fn only_circulating_tokens_block_close_of_resolved_market() {
+        let (mut market, mut book) = parimutuel_book(&[600, 400], &[600, 400]);
+        market.resolve(&mut book, &[1, 0], 1).unwrap();
+        market.creator_peg_claimed = true;
+
+        assert!(market.has_outstanding_liabilities(&book, &[600, 400]).unwrap());
+        // Losing tokens are owed nothing
+        assert!(!market.has_outstanding_liabilities(&book, &[0, 400]).unwrap());
+        // Once every token is redeemed, only the rounding remainder is left
+        assert!(!market.has_outstanding_liabilities(&book, &[0, 0]).unwrap());
+    }
+
+    #[test]
+    fn parimutuel_split_goes_to_held_outcomes() {
+        let (mut market, mut book) = parimutuel_book(&[600, 300, 100], &[600, 300, 0]);
+        market.resolve(&mut book, &[1, 1, 2], 4).unwrap();
+
+        // The unheld outcome's half is shared by the held ones
+        assert_eq!(book.payouts(), &[500, 500, 0]);
+        assert_eq!(market.quote_redemption(&book, 1, 300).unwrap(), 500);
+    }
+
+    #[test]
+    fn parimutuel_unheld_winner_refunds_each_pool() {
+        let (mut market, mut book) = parimutuel_book(&[600, 300, 100], &[600, 300, 0]);
+        market.resolve(&mut book, &[0, 0, 1], 1).unwrap();
+
+        assert_eq!(book.payouts(), &[600, 300, 0]);
+        assert_eq!(market.quote_redemption(&book, 0, 60).unwrap(), 60);
+        assert_eq!(market.quote_redemption(&book, 2, 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn payout_vector_must_sum_to_a_nonzero_denominator() {
+        let (market, _) = parimutuel_book(&[0, 0], &[]);
+        assert!(market.validate_payout_vector(&[1, 3], 4).is_ok());
+        for (numerators, denominator) in [(&[0, 0][..], 0), (&[1, 1][..], 3), (&[1][..], 1), (&[u32::MAX, 1][..], 0)] {
+            assert_eq!(
+                market.validate_payout_vector(numerators, denominator).err(),
+                Some(crate::errors::BelievError::InvalidPayoutVector.into())
+            );
+        }
+    }
+
+    #[test]
+    fn latest_attestation_wins_until_the_dispute_window_closes() {
+        let (mut market, _) = parimutuel_book(&[0, 0], &[]);
+        market.status = MarketStatus::Closed;
+        market.resolve_at = 1_000;
+        let invalid = Some(crate::errors::BelievError::InvalidAttestationTimestamp.into());
+
+        // Signed before resolution time, or in the future
+        assert_eq!(market.check_attestation(999, 1_500).err(), invalid);
+        assert_eq!(market.check_attestation(1_501, 1_500).err(), invalid);
+        assert!(market.check_attestation(1_200, 1_500).is_ok());
+
+        market.status = MarketStatus::Resolved;
+        market.attested_at = 1_200;
+        market.dispute_ends_at = 5_000;
+
+        // Only a later attestation replaces the result
+        assert_eq!(market.check_attestation(1_200, 1_600).err(), invalid);
+        assert_eq!(market.check_attestation(1_100, 1_600).err(), invalid);
+        assert!(market.check_attestation(1_300, 1_600).is_ok());
+
+        assert_eq!(
+            market.check_attestation(1_300, 5_000).err(),
+            Some(crate::errors::BelievError::DisputeWindowClosed.into())
+        );
+        market.status = MarketStatus::Disputed;
+        assert_eq!(
+            market.check_attestation(1_300, 1_600).err(),
+            Some(crate::errors::BelievError::MarketAlreadyResolved.into())
+        );
+    }
+
+    #[test]
+    fn accrues_the_whole_trading_fee() {
+        let (market, mut book) = parimutuel_book(&[0, 0], &[]);
+        market.accrue_fee(&mut book, 1, 25).unwrap();
+        market.accrue_fee(&mut book, 1, 10).unwrap();
+        assert_eq!(book.fees(), &[0, 35]);
+        assert_eq!(book.total_fees(), 35);
+    }
+
+    #[test]
+    fn fee_shares_pay_out_every_accrued_fee() {
+        let (mut market, _) = parimutuel_book(&[0, 0], &[]);
+        market.invitor = Some(Pubkey::new_unique());
+        assert_eq!(market.fee_shares(1_000).unwrap(), (800, 100, 100));
+        // Rounding remainders go to the platform
+        assert_eq!(market.fee_shares(19).unwrap(), (15, 1, 3));
+
+        // Without an invitor the platform takes the invitor's share
+        market.invitor = None;
+        assert_eq!(market.fee_shares(1_000).unwrap(), (800, 0, 200));
+    }
+
+    #[test]
+    fn space_fits_a_market_with_every_field_at_its_maximum() {
+        let mut market = blank_market();
+        market.legacy_address = Some(Pubkey::new_unique());
+        market.invitor = Some(Pubkey::new_unique());
+        market.referrer = Some(Pubkey::new_unique());
+        market.tags = vec!["t".repeat(Market::MAX_TAG_LEN); Market::MAX_TAGS];
+        market.price_bands = Some(PriceBands {
+            feed: Pubkey::new_unique(),
+            expo: -8,
+            thresholds: vec![i64::MAX; Market::MAX_OUTCOMES as usize - 1],
+        });
+        market.publisher = Some(DataPublisher {
+            publisher: Pubkey::new_unique(),
+            event_id: "e".repeat(crate::constants::MAX_EVENT_ID_LEN),
+        });
+        market.challenger = Some(Pubkey::new_unique());
+        market.proposer = Some(Pubkey::new_unique());
+        market.void_reason = Some(VoidReason::ResolutionExpired);
+
+        assert_eq!(
+            market.try_to_vec().unwrap().len() + 8,
+            Market::space(&market.tags, &market.price_bands, &market.publisher)
+        );
+    }
+}
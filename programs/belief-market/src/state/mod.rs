@@ -1,7 +1,23 @@
 pub mod global_state;
 pub mod user_profile;
+pub mod referrer_code;
 pub mod market;
+pub mod outcome_book;
+pub mod legacy_market;
+pub mod legacy_global_state;
+pub mod order;
+pub mod cost_basis;
+pub mod resolver_council;
+pub mod resolution_votes;
 
 pub use global_state::GlobalState;
 pub use user_profile::UserProfile;
-pub use market::Market;
+pub use referrer_code::ReferrerCode;
+pub use market::{DataPublisher, Market, MarketStatus, PriceBands, PricingMode, VoidReason};
+pub use outcome_book::OutcomeBook;
+pub use legacy_market::LegacyMarket;
+pub use legacy_global_state::LegacyGlobalState;
+pub use order::{Order, OrderSide};
+pub use cost_basis::CostBasis;
+pub use resolver_council::ResolverCouncil;
+pub use resolution_votes::{CouncilVote, ResolutionVotes};
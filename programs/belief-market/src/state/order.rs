@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+/// Side of a limit order
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OrderSide {
+    /// Buy shares; collateral is escrowed in the market vault
+    Bid,
+    /// Sell shares; shares are burned from the owner's token account into escrow
+    Ask,
+}
+
+/// A resting limit order for shares of one outcome
+#[account]
+pub struct Order {
+    /// Market this order trades in (its pre-migration address for migrated markets)
+    pub market: Pubkey,
+    /// Order owner
+    pub owner: Pubkey,
+    /// Sequential order id within the market (lower ids rest first)
+    pub order_id: u64,
+    /// Outcome being traded
+    pub outcome_index: u8,
+    /// Bid or ask
+    pub side: OrderSide,
+    /// Limit price in collateral per share, scaled by `ORDER_PRICE_SCALE`
+    pub price: u64,
+    /// Original order size in shares
+    pub quantity: u64,
+    /// Shares not yet filled
+    pub remaining: u64,
+    /// Collateral (bids) or shares (asks) still held in escrow
+    pub escrow: u64,
+    /// Order placement timestamp
+    pub created_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl Order {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 1 + 1 + 8 + 8 + 8 + 8 + 8 + 1;
+
+    /// Whether the order can still be matched
+    pub fn is_open(&self) -> bool {
+        self.remaining > 0
+    }
+}
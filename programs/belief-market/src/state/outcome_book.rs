@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use crate::constants::{MAX_OUTCOMES, MAX_OUTCOME_LABEL_LEN};
+
+const MAX_OUTCOMES_USIZE: usize = MAX_OUTCOMES as usize;
+
+/// Per-outcome state of a market, kept zero-copy in its own account
+///
+/// Trades borrow the account in place instead of deserializing every outcome,
+/// so a parimutuel trade touches only the entries of the outcome it trades.
+/// Arrays are sized for `MAX_OUTCOMES`; only the first `num_outcomes` entries
+/// are used. The account is larger than a program can create, so the client
+/// allocates it (owned by this program, `OutcomeBook::LEN` bytes) in the same
+/// transaction as `create_market`.
+#[account(zero_copy)]
+pub struct OutcomeBook {
+    /// Market this book belongs to
+    pub market: Pubkey,
+    /// Total amount wagered on each outcome
+    pub outcome_pools: [u64; MAX_OUTCOMES_USIZE],
+    /// Number of shares issued for each outcome
+    pub outcome_shares: [u64; MAX_OUTCOMES_USIZE],
+    /// Outcome shares held by the CPMM pool (unused for other pricing modes)
+    pub amm_balances: [u64; MAX_OUTCOMES_USIZE],
+    /// Collateral reserved at resolution for each outcome's outstanding shares
+    pub payout_pools: [u64; MAX_OUTCOMES_USIZE],
+    /// Accumulated trading fees per outcome (not yet withdrawn)
+    pub accumulated_fees: [u64; MAX_OUTCOMES_USIZE],
+    /// SPL mint of each outcome's share token (default key until initialized)
+    pub outcome_mints: [Pubkey; MAX_OUTCOMES_USIZE],
+    /// UTF-8 label of each outcome, zero-padded
+    pub outcome_labels: [[u8; MAX_OUTCOME_LABEL_LEN]; MAX_OUTCOMES_USIZE],
+    /// Share of the payout each outcome receives, over the market's `payout_denominator`
+    pub payout_numerators: [u32; MAX_OUTCOMES_USIZE],
+    /// Number of outcomes in use
+    pub num_outcomes: u16,
+    pub padding: [u8; 6],
+}
+
+impl OutcomeBook {
+    pub const LEN: usize = 8 + std::mem::size_of::<OutcomeBook>();
+
+    pub fn outcome_count(&self) -> usize {
+        self.num_outcomes as usize
+    }
+
+    pub fn pools(&self) -> &[u64] {
+        &self.outcome_pools[..self.outcome_count()]
+    }
+
+    pub fn shares(&self) -> &[u64] {
+        &self.outcome_shares[..self.outcome_count()]
+    }
+
+    pub fn balances(&self) -> &[u64] {
+        &self.amm_balances[..self.outcome_count()]
+    }
+
+    pub fn payouts(&self) -> &[u64] {
+        &self.payout_pools[..self.outcome_count()]
+    }
+
+    pub fn fees(&self) -> &[u64] {
+        &self.accumulated_fees[..self.outcome_count()]
+    }
+
+    pub fn mints(&self) -> &[Pubkey] {
+        &self.outcome_mints[..self.outcome_count()]
+    }
+
+    pub fn numerators(&self) -> &[u32] {
+        &self.payout_numerators[..self.outcome_count()]
+    }
+
+    /// Label of an outcome, without its zero padding
+    pub fn label(&self, outcome_idx: usize) -> String {
+        let bytes = &self.outcome_labels[outcome_idx];
+        let len = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+        String::from_utf8_lossy(&bytes[..len]).into_owned()
+    }
+
+    /// Set the label of an outcome, failing if it is longer than `MAX_OUTCOME_LABEL_LEN`
+    pub fn set_label(&mut self, outcome_idx: usize, label: &str) -> Result<()> {
+        require!(
+            label.len() <= MAX_OUTCOME_LABEL_LEN,
+            crate::errors::BelievError::StringTooLong
+        );
+        let mut bytes = [0u8; MAX_OUTCOME_LABEL_LEN];
+        bytes[..label.len()].copy_from_slice(label.as_bytes());
+        self.outcome_labels[outcome_idx] = bytes;
+        Ok(())
+    }
+
+    /// Trading fees not yet withdrawn across all outcomes
+    pub fn total_fees(&self) -> u64 {
+        self.fees().iter().sum()
+    }
+
+    /// Total amount in all outcome pools
+    pub fn total_pool(&self) -> u64 {
+        self.pools().iter().sum()
+    }
+}
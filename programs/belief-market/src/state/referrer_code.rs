@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use super::UserProfile;
+
+/// Registry entry mapping a normalized referrer code to the user who owns it
+#[account]
+pub struct ReferrerCode {
+    /// Normalized code, also used as the PDA seed
+    pub code: String,
+    /// User who owns the code
+    pub owner: Pubkey,
+    /// Profile of the owner
+    pub profile: Pubkey,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl ReferrerCode {
+    pub const LEN: usize = 8 + 4 + UserProfile::MAX_REFERRER_CODE_LEN + 32 + 32 + 1;
+
+    /// Normalize a referrer code so that codes differing only in case collide
+    /// Codes must be 1-20 ASCII letters, digits, `-` or `_`.
+    pub fn normalize(code: &str) -> Result<String> {
+        require!(
+            code.len() <= UserProfile::MAX_REFERRER_CODE_LEN,
+            crate::errors::BelievError::StringTooLong
+        );
+        require!(
+            !code.is_empty()
+                && code.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_'),
+            crate::errors::BelievError::ReferrerCodeInvalid
+        );
+        Ok(code.to_ascii_lowercase())
+    }
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+
+/// A council member's vote on a market's payout vector
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct CouncilVote {
+    /// Voting member
+    pub member: Pubkey,
+    /// Proposed payout numerator of each outcome
+    pub payout_numerators: Vec<u32>,
+    /// Sum of the proposed numerators
+    pub payout_denominator: u32,
+}
+
+impl CouncilVote {
+    pub const MAX_LEN: usize = 32 + 4 + 4 * crate::constants::MAX_OUTCOMES as usize + 4;
+
+    /// Whether two votes describe the same payouts, even if scaled differently
+    /// A vote with a zero denominator agrees with nothing
+    pub fn agrees_with(&self, other: &CouncilVote) -> bool {
+        self.payout_denominator > 0
+            && other.payout_denominator > 0
+            && self.payout_numerators.len() == other.payout_numerators.len()
+            && self
+                .payout_numerators
+                .iter()
+                .zip(&other.payout_numerators)
+                .all(|(&a, &b)| {
+                    a as u64 * other.payout_denominator as u64 == b as u64 * self.payout_denominator as u64
+                })
+    }
+}
+
+/// Council votes cast on one market's resolution
+#[account]
+pub struct ResolutionVotes {
+    /// Market being resolved
+    pub market: Pubkey,
+    /// Council voting on it
+    pub council: Pubkey,
+    /// Latest vote of each member that has voted
+    pub votes: Vec<CouncilVote>,
+    /// Number of votes cast that disagreed with an earlier vote
+    pub mismatches: u32,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl ResolutionVotes {
+    pub const LEN: usize = 8 + 32 + 32
+        + 4 + CouncilVote::MAX_LEN * crate::constants::MAX_COUNCIL_MEMBERS
+        + 4 + 1;
+
+    /// Number of recorded votes agreeing with `vote`, including its own
+    pub fn matching(&self, vote: &CouncilVote) -> usize {
+        self.votes.iter().filter(|cast| cast.agrees_with(vote)).count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vote(payout_numerators: &[u32], payout_denominator: u32) -> CouncilVote {
+        CouncilVote {
+            member: Pubkey::new_unique(),
+            payout_numerators: payout_numerators.to_vec(),
+            payout_denominator,
+        }
+    }
+
+    fn votes(cast: Vec<CouncilVote>) -> ResolutionVotes {
+        ResolutionVotes {
+            market: Pubkey::default(),
+            council: Pubkey::default(),
+            votes: cast,
+            mismatches: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn scaled_votes_agree() {
+        assert!(vote(&[1, 1], 2).agrees_with(&vote(&[50, 50], 100)));
+        assert!(!vote(&[1, 0], 1).agrees_with(&vote(&[0, 1], 1)));
+        assert!(!vote(&[1, 0], 1).agrees_with(&vote(&[1, 0, 0], 1)));
+    }
+
+    #[test]
+    fn zero_vote_does_not_count_toward_threshold() {
+        let zero = vote(&[0, 0], 0);
+        let recorded = votes(vec![vote(&[1, 0], 1), vote(&[0, 1], 1), zero.clone()]);
+
+        assert_eq!(recorded.matching(&zero), 0);
+        assert_eq!(recorded.matching(&vote(&[2, 0], 2)), 1);
+    }
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+/// M-of-N council that can be named as a market's resolver
+#[account]
+pub struct ResolverCouncil {
+    /// Key that created the council
+    pub authority: Pubkey,
+    /// Id distinguishing the authority's councils
+    pub council_id: u64,
+    /// Keys allowed to vote on resolutions
+    pub members: Vec<Pubkey>,
+    /// Matching votes needed to resolve a market
+    pub threshold: u8,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl ResolverCouncil {
+    pub const LEN: usize = 8 + 32 + 8 + 4 + 32 * crate::constants::MAX_COUNCIL_MEMBERS + 1 + 1;
+
+    pub fn is_member(&self, key: &Pubkey) -> bool {
+        self.members.contains(key)
+    }
+}
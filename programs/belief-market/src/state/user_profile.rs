@@ -7,7 +7,7 @@ pub struct UserProfile {
     pub owner: Pubkey,
     /// One-time binding to invitor (cannot be changed)
     pub invitor: Option<Pubkey>,
-    /// Referrer code given to others (user sets their own referrer code)
+    /// Normalized referrer code given to others, registered in its `ReferrerCode` PDA
     pub referrer_code: String,
     /// Bump seed for PDA derivation
     pub bump: u8,